@@ -1,5 +1,91 @@
-use rusty_xmas::ui::start_menu;
+use std::{env, process};
+
+use rusty_xmas::{
+    solves::{
+        data_dir::{is_valid_profile_name, DataDir, DEFAULT_PROFILE},
+        new_day,
+    },
+    ui::start_menu,
+};
+
+const USAGE: &str = "Usage: rusty_xmas [--data-root <path>] [--profile <name>] [new <year> <day>]";
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    process::exit(2);
+}
 
 fn main() {
+    let mut data = DataDir::from_env();
+    let mut args = env::args().skip(1);
+    let mut scaffold = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-root" => {
+                let Some(root) = args.next() else {
+                    exit_with_usage("--data-root needs a path.");
+                };
+
+                data = data.with_root(root);
+            }
+            "--profile" => {
+                let Some(profile) = args.next() else {
+                    exit_with_usage("--profile needs a name.");
+                };
+
+                let profile = match profile.as_str() {
+                    DEFAULT_PROFILE => None,
+                    name if is_valid_profile_name(name) => Some(name),
+                    name => exit_with_usage(&format!(
+                        "{name} isn't a valid profile name, use letters, digits, - and _."
+                    )),
+                };
+
+                data = data.with_profile(profile);
+            }
+            "new" => {
+                let mut number = || args.next().and_then(|arg| arg.parse::<u64>().ok());
+
+                let (Some(year), Some(day)) = (number(), number()) else {
+                    exit_with_usage("new needs a year and a day.");
+                };
+
+                scaffold = Some((year, day));
+            }
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => exit_with_usage(&format!("Unknown argument {arg}.")),
+        }
+    }
+
+    data.install();
+
+    if let Some((year, day)) = scaffold {
+        return scaffold_day(year, day);
+    }
+
     start_menu();
 }
+
+fn scaffold_day(year: u64, day: u64) {
+    let (written, problems) = match new_day(year, day) {
+        Ok(scaffolded) => scaffolded,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+
+    for e in problems {
+        eprintln!("{e}\n{}", e.next_step());
+    }
+
+    println!("Rebuild to pick up day {day} of {year}.");
+}
@@ -1,80 +1,885 @@
-use reqwest::{blocking::Client, header::COOKIE};
-
-use self::{y2019::get_2019_solutions, year::AdventOfCodeYear};
+use self::{
+    bench::{benchmark, BenchSettings, Benchmark},
+    cache::{quarantine, quarantine_cached, validate_input, InputMeta},
+    catalog::{event_days, event_years, EventDay},
+    client::AocClient,
+    custom::CustomInput,
+    data_dir::DataDir,
+    earned::{parse_answers, parse_calendar, EarnedDay, EarnedYear},
+    error::DataError,
+    examples::Example,
+    known::{verify, Check, KnownAnswers},
+    leaderboard::{Leaderboard, LEADERBOARD_REFRESH, LEADERBOARD_VAR},
+    ledger::Ledger,
+    provider::InputProvider,
+    puzzle::title,
+    scaffold::{check_new_day, find_source_root, scaffold_day, ScaffoldError},
+    session::{find_session, find_session_for, is_logged_out, SessionSource},
+    submit::{submit, SubmitError, Verdict},
+    unlock::{is_unlocked, Clock, SystemClock},
+    year::{AdventOfCodeDay, AdventOfCodeYear, Part},
+};
 use std::{
-    error::Error,
-    fs::{create_dir_all, File},
-    io::{self, Read, Write},
+    env,
+    fs::{create_dir_all, metadata, read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
 
+pub mod bench;
+pub mod cache;
+pub mod catalog;
+pub mod client;
+pub mod custom;
+pub mod data_dir;
+pub mod earned;
+pub mod error;
+pub mod examples;
+pub mod html;
+pub mod known;
+pub mod leaderboard;
+pub mod ledger;
+pub mod mock_server;
+pub mod params;
+pub mod provider;
+pub mod puzzle;
+pub mod scaffold;
+pub mod session;
+pub mod solution;
+pub mod submit;
+pub mod throttle;
+pub mod unlock;
 pub mod y2019;
 pub mod year;
 
+/// Every event that has started, with the solutions of the years that have any.
 #[must_use]
 pub fn get_years<'a>() -> Vec<AdventOfCodeYear<'a>> {
-    vec![get_2019_solutions()]
+    let mut solved = vec![y2019::get_2019_solutions()];
+
+    let mut years: Vec<AdventOfCodeYear> = event_years(&SystemClock)
+        .map(|year| {
+            solved
+                .iter()
+                .position(|solved| solved.year == year)
+                .map_or_else(
+                    || AdventOfCodeYear::new(year, vec![]),
+                    |idx| solved.swap_remove(idx),
+                )
+        })
+        .collect();
+
+    years.append(&mut solved);
+    years.sort_by_key(|year| year.year);
+
+    years
+}
+
+/// The calendar of `year`, titled from its solutions or from cached puzzle pages.
+#[must_use]
+pub fn get_event(year: &AdventOfCodeYear) -> Vec<EventDay> {
+    let data = DataDir::current();
+
+    event_days(year.year, |day| {
+        if let Some(solution) = year.day(day) {
+            return Some(solution.name.to_string());
+        }
+
+        let page = read_cache_file(&data.puzzle(year.year, day)).ok()??;
+
+        title(&page)
+    })
 }
 
 /// # Errors
 /// This function errors if it can't request data from Advent of Code inputs.
-pub fn get_data(year: u64, day: u64) -> Result<String, Box<dyn Error>> {
-    read_cache(year, day).or_else(|_| {
-        let session = get_session()
-            .expect("Couldn't get session key to request data from. Add it to /data/session.txt");
-        let data = request_data(year, day, &session)?;
+pub fn get_data(year: u64, day: u64) -> Result<String, DataError> {
+    get_profile_data(DataDir::current(), year, day)
+}
+
+/// Same as [`get_data`], but with the cache and session of another profile.
+///
+/// # Errors
+/// This function errors if the input isn't cached and can't be requested with the profile's session.
+pub fn get_profile_data(data: &DataDir, year: u64, day: u64) -> Result<String, DataError> {
+    if let Some(input) = read_cached_input(data, &SystemClock, year, day)? {
+        return Ok(input);
+    }
+
+    let client = AocClient::from_env(&find_session_for(data)?.token)?;
+
+    fetch_data(data, &client, &SystemClock, year, day)
+}
+
+/// The default profile followed by every named one.
+///
+/// # Errors
+/// This function errors if the profiles can't be listed.
+pub fn get_profiles() -> Result<Vec<DataDir>, DataError> {
+    let data = DataDir::current();
+
+    let names = data
+        .profiles()
+        .map_err(|e| DataError::cache_io(data.root().join("profiles"), e))?;
+
+    let mut profiles = vec![data.clone().with_profile(None)];
+    profiles.extend(
+        names
+            .iter()
+            .map(|name| data.clone().with_profile(Some(name))),
+    );
+
+    Ok(profiles)
+}
+
+/// Same as [`get_data`], but with the cache in `data`, inputs missing from it
+/// coming from `provider`, and `clock` deciding whether the puzzle has unlocked yet.
+///
+/// # Errors
+/// This function errors if the input isn't cached and `provider` fails to supply it.
+pub fn get_data_with(
+    data: &DataDir,
+    provider: &dyn InputProvider,
+    clock: &dyn Clock,
+    year: u64,
+    day: u64,
+) -> Result<String, DataError> {
+    read_cached_input(data, clock, year, day)?
+        .map_or_else(|| fetch_data(data, provider, clock, year, day), Ok)
+}
+
+fn fetch_data(
+    data: &DataDir,
+    provider: &dyn InputProvider,
+    clock: &dyn Clock,
+    year: u64,
+    day: u64,
+) -> Result<String, DataError> {
+    if !is_unlocked(clock, year, day) {
+        return Err(DataError::NotUnlocked { year, day });
+    }
+
+    let input = provider.input(year, day)?;
+
+    if let Err(reason) = validate_input(&input) {
+        let _unused_result = quarantine(data, year, day, &input, clock.now()).inspect_err(|_| {
+            println!("Couldn't write to quarantine!");
+        });
+
+        return Err(DataError::InvalidContent(format!(
+            "the input for day {day} of {year} wasn't cached because {reason}"
+        )));
+    }
+
+    let meta = InputMeta {
+        fetched: clock.now(),
+        session: provider.session_fingerprint(),
+    };
 
-        let _unused_result = write_cache(year, day, &data).map_err(|e| {
+    let _unused_result = write_cache_file(&data.input(year, day), &input)
+        .and_then(|()| {
+            meta.save(data, year, day)
+                .map_err(|e| DataError::cache_io(data.input_meta(year, day), e))
+        })
+        .inspect_err(|_| {
             println!("Couldn't write to cache!");
-            e
         });
 
-        Ok(data)
-    })
+    Ok(input)
+}
+
+/// Gets the puzzle page for a day, which is only fetched when it isn't cached yet.
+///
+/// # Errors
+/// This function errors if the page isn't cached and can't be requested from Advent of Code.
+pub fn get_puzzle(year: u64, day: u64) -> Result<String, DataError> {
+    read_cache_file(&DataDir::current().puzzle(year, day))?
+        .map_or_else(|| refresh_puzzle(year, day), Ok)
+}
+
+/// Fetches the puzzle page again, e.g. to pick up part 2 after solving part 1.
+///
+/// # Errors
+/// This function errors if the page can't be requested from Advent of Code.
+pub fn refresh_puzzle(year: u64, day: u64) -> Result<String, DataError> {
+    if !is_unlocked(&SystemClock, year, day) {
+        return Err(DataError::NotUnlocked { year, day });
+    }
+
+    fetch_puzzle(DataDir::current(), &aoc_client()?, year, day)
+}
+
+fn fetch_puzzle(
+    data: &DataDir,
+    client: &AocClient,
+    year: u64,
+    day: u64,
+) -> Result<String, DataError> {
+    let page = client.get(&format!("/{year}/day/{day}"))?;
+
+    let _unused_result = write_cache_file(&data.puzzle(year, day), &page).inspect_err(|_| {
+        println!("Couldn't write to cache!");
+    });
+
+    Ok(page)
+}
+
+/// Stars and answers earned on the site as of the last sync.
+#[must_use]
+pub fn get_earned(year: u64) -> EarnedYear {
+    EarnedYear::load(DataDir::current(), year)
+}
+
+/// Reads earned stars from the year's calendar, and the answers for them from
+/// the day pages. Cached day pages are only fetched again when they're missing answers.
+///
+/// # Errors
+/// This function errors if there's no session key or a page can't be fetched.
+pub fn sync_earned(year: u64) -> Result<EarnedYear, DataError> {
+    sync_earned_with(DataDir::current(), &aoc_client()?, year)
+}
+
+/// Same as [`sync_earned`], with the cache in `data` and pages coming from `client`.
+///
+/// # Errors
+/// This function errors if the session isn't logged in or a page can't be fetched.
+pub fn sync_earned_with(
+    data: &DataDir,
+    client: &AocClient,
+    year: u64,
+) -> Result<EarnedYear, DataError> {
+    let (status, calendar) = client.get_with_status(&format!("/{year}"))?;
+
+    if is_logged_out(status, &calendar) {
+        return Err(DataError::ExpiredSession);
+    }
+
+    if !(200..300).contains(&status) {
+        return Err(DataError::HttpStatus {
+            status,
+            url: client.url(&format!("/{year}")),
+        });
+    }
+
+    let _unused_result = write_cache_file(&data.calendar(year), &calendar).inspect_err(|_| {
+        println!("Couldn't write to cache!");
+    });
+
+    let mut earned = EarnedYear::default();
+
+    for (day, stars) in parse_calendar(&calendar) {
+        let mut earned_day = EarnedDay {
+            stars,
+            answers: vec![],
+        };
+
+        if stars > 0 {
+            let mut answers = read_cache_file(&data.puzzle(year, day))?
+                .map(|page| parse_answers(&page))
+                .unwrap_or_default();
+
+            if answers.len() < earned_day.expected_answers(year, day) {
+                answers = parse_answers(&fetch_puzzle(data, client, year, day)?);
+            }
+
+            earned_day.answers = answers;
+        }
+
+        if !earned_day.answers.is_empty() {
+            let mut known = KnownAnswers::load(data, year, day);
+
+            for (part, answer) in [Part::One, Part::Two].into_iter().zip(&earned_day.answers) {
+                known.set(part, answer);
+            }
+
+            known
+                .save(data, year, day)
+                .map_err(|e| DataError::cache_io(data.known_answers(year, day), e))?;
+        }
+
+        earned.days.insert(day, earned_day);
+    }
+
+    earned
+        .save(data, year)
+        .map_err(|e| DataError::cache_io(data.earned(year), e))?;
+
+    Ok(earned)
+}
+
+/// Gets the example fixtures for a day. Parts without fixtures are extracted
+/// from the puzzle page, so part 2 shows up once the page has been refreshed.
+///
+/// # Errors
+/// This function errors if there are no fixtures and the puzzle page can't be loaded.
+pub fn get_examples(year: u64, day: u64) -> Result<Vec<Example>, DataError> {
+    let data = DataDir::current();
+
+    let mut saved = examples::load(data, year, day)
+        .map_err(|e| DataError::cache_io(data.examples(year, day), e))?;
+
+    if saved.len() == 2 {
+        return Ok(saved);
+    }
+
+    let page = match get_puzzle(year, day) {
+        Ok(page) => page,
+        Err(_) if !saved.is_empty() => return Ok(saved),
+        Err(e) => return Err(e),
+    };
+
+    let missing: Vec<Example> = examples::extract(&page)
+        .into_iter()
+        .filter(|example| saved.iter().all(|saved| saved.part != example.part))
+        .collect();
+
+    let _unused_result = examples::save(data, year, day, &missing).inspect_err(|_| {
+        println!("Couldn't write example fixtures!");
+    });
+
+    saved.extend(missing);
+    saved.sort_by_key(|example| example.part);
+
+    Ok(saved)
 }
 
-fn get_session() -> Result<String, io::Error> {
-    let mut file = File::open("data/session.txt")?;
-    let mut data = String::new();
+/// Generates a new day in the checkout of this repository around the working directory
+/// and prefetches its input.
+///
+/// The day is named after its puzzle, with tests seeded from the puzzle's examples.
+/// Returns the files that were written along with whatever couldn't be fetched,
+/// which doesn't stop the day being generated.
+///
+/// # Errors
+/// This function errors if the day can't be generated.
+pub fn new_day(year: u64, day: u64) -> Result<(Vec<PathBuf>, Vec<DataError>), ScaffoldError> {
+    let directory = env::current_dir().map_err(|source| ScaffoldError::Io {
+        path: PathBuf::from("."),
+        source,
+    })?;
+    let src = &find_source_root(&directory)?;
+    check_new_day(src, year, day)?;
+
+    let mut problems = vec![];
+
+    let (name, examples) = match get_puzzle(year, day) {
+        Ok(page) => (
+            title(&page),
+            get_examples(year, day).unwrap_or_else(|e| {
+                problems.push(e);
+                vec![]
+            }),
+        ),
+        Err(e) => {
+            problems.push(e);
+            (None, vec![])
+        }
+    };
 
-    file.read_to_string(&mut data)?;
+    let name = name.unwrap_or_else(|| format!("Day {day}"));
+    let written = scaffold_day(src, year, day, &name, &examples)?;
 
-    Ok(data.trim().to_string())
+    if let Err(e) = get_data(year, day) {
+        problems.push(e);
+    }
+
+    Ok((written, problems))
 }
 
-fn read_cache(year: u64, day: u64) -> Result<String, io::Error> {
-    let mut file = File::open(format!("data/cache/{year}/day{day}.txt"))?;
-    let mut data = String::new();
+/// Gets the named alternative inputs for a day from `custom/{year}/day{day}`.
+///
+/// # Errors
+/// This function errors if a custom input exists but can't be read.
+pub fn get_custom_inputs(year: u64, day: u64) -> Result<Vec<CustomInput>, DataError> {
+    let data = DataDir::current();
+
+    custom::load(data, year, day).map_err(|e| DataError::cache_io(data.custom(year, day), e))
+}
+
+/// The private leaderboard to show, from `$AOC_LEADERBOARD_ID` or `leaderboard.txt` in the data directory.
+#[must_use]
+pub fn leaderboard_id() -> Option<u64> {
+    env::var(LEADERBOARD_VAR)
+        .ok()
+        .or_else(|| read_to_string(DataDir::current().leaderboard_id_file()).ok())
+        .and_then(|id| id.trim().parse().ok())
+}
+
+/// # Errors
+/// This function errors if the id can't be stored.
+pub fn save_leaderboard_id(id: u64) -> Result<(), DataError> {
+    write_cache_file(&DataDir::current().leaderboard_id_file(), &id.to_string())
+}
 
-    file.read_to_string(&mut data)?;
+/// Gets a private leaderboard along with how old it is. It's only fetched again
+/// once the cached copy is older than [`LEADERBOARD_REFRESH`].
+///
+/// # Errors
+/// This function errors if the leaderboard has to be fetched and can't be.
+pub fn get_leaderboard(year: u64, id: u64) -> Result<(Leaderboard, Duration), DataError> {
+    get_leaderboard_with(DataDir::current(), &aoc_client()?, &SystemClock, year, id)
+}
 
-    Ok(data)
+/// Same as [`get_leaderboard`], with the cache in `data`, fetches going to `client`
+/// and `clock` deciding how old the cached copy is.
+///
+/// # Errors
+/// This function errors if the leaderboard has to be fetched and can't be.
+pub fn get_leaderboard_with(
+    data: &DataDir,
+    client: &AocClient,
+    clock: &dyn Clock,
+    year: u64,
+    id: u64,
+) -> Result<(Leaderboard, Duration), DataError> {
+    cached_leaderboard(data, clock, year, id)?
+        .map_or_else(|| fetch_leaderboard(data, client, year, id), Ok)
 }
 
-fn write_cache(year: u64, day: u64, data: &str) -> Result<(), Box<dyn Error>> {
-    create_dir_all(format!("data/cache/{year}"))?;
+fn cached_leaderboard(
+    data: &DataDir,
+    clock: &dyn Clock,
+    year: u64,
+    id: u64,
+) -> Result<Option<(Leaderboard, Duration)>, DataError> {
+    let path = data.leaderboard(year, id);
 
-    let mut file = File::create(format!("data/cache/{year}/day{day}.txt"))?;
-    file.write_all(data.as_bytes())?;
+    let Ok(modified) = metadata(&path).and_then(|metadata| metadata.modified()) else {
+        return Ok(None);
+    };
 
-    Ok(())
+    let age = clock.now().duration_since(modified).unwrap_or_default();
+
+    if age >= LEADERBOARD_REFRESH {
+        return Ok(None);
+    }
+
+    match read_cache_file(&path)? {
+        Some(json) => Ok(Some((Leaderboard::parse(&json)?, age))),
+        None => Ok(None),
+    }
 }
 
-fn request_data(year: u64, day: u64, session: &str) -> Result<String, Box<dyn Error>> {
-    let client = Client::builder().cookie_store(true).build()?;
-    let mut data = String::new();
+fn fetch_leaderboard(
+    data: &DataDir,
+    client: &AocClient,
+    year: u64,
+    id: u64,
+) -> Result<(Leaderboard, Duration), DataError> {
+    let path = format!("/{year}/leaderboard/private/view/{id}.json");
+    let (status, json) = client.get_with_status(&path)?;
 
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    client
-        .get(url)
-        .header(COOKIE, format!("session={session}"))
-        .send()?
-        .read_to_string(&mut data)?;
+    if is_logged_out(status, &json) {
+        return Err(DataError::ExpiredSession);
+    }
 
-    Ok(data)
+    if !(200..300).contains(&status) {
+        return Err(DataError::HttpStatus {
+            status,
+            url: client.url(&path),
+        });
+    }
+
+    let leaderboard = Leaderboard::parse(&json)?;
+
+    let _unused_result = write_cache_file(&data.leaderboard(year, id), &json).inspect_err(|_| {
+        println!("Couldn't write to cache!");
+    });
+
+    Ok((leaderboard, Duration::ZERO))
+}
+
+/// Submits `answer` unless the ledger already knows it can't be right, then
+/// records the verdict.
+///
+/// # Errors
+/// This function errors if the ledger rejects the answer, there's no session
+/// key or the answer couldn't be sent.
+pub fn submit_answer(
+    year: u64,
+    day: u64,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    let data = DataDir::current();
+
+    let mut ledger = Ledger::load(data, year, day)
+        .map_err(|e| DataError::cache_io(data.ledger(year, day), e))?;
+    ledger.check(part, answer)?;
+
+    let verdict = submit(&aoc_client()?, year, day, part, answer)?;
+
+    ledger.record(part, answer, &verdict);
+
+    let _unused_result = ledger.save(data, year, day).inspect_err(|_| {
+        println!("Couldn't write to ledger!");
+    });
+
+    if verdict == Verdict::Correct {
+        let _unused_result = lock_in_answer(year, day, part, answer).inspect_err(|_| {
+            println!("Couldn't write to known answers!");
+        });
+    }
+
+    Ok(verdict)
+}
+
+#[must_use]
+pub fn get_known_answers(year: u64, day: u64) -> KnownAnswers {
+    KnownAnswers::load(DataDir::current(), year, day)
+}
+
+/// Stores `answer` as the accepted answer for a part, which [`verify_day`] checks against.
+///
+/// # Errors
+/// This function errors if the known answers can't be written.
+pub fn lock_in_answer(year: u64, day: u64, part: Part, answer: &str) -> Result<(), DataError> {
+    let data = DataDir::current();
+
+    let mut known = KnownAnswers::load(data, year, day);
+    known.set(part, answer);
+
+    known
+        .save(data, year, day)
+        .map_err(|e| DataError::cache_io(data.known_answers(year, day), e))
+}
+
+/// Reruns `day` on its cached input and compares the results to the known answers.
+/// Returns `None` if the input isn't cached, since nothing is known about it then.
+///
+/// # Errors
+/// This function errors if the cached input can't be read.
+pub fn verify_day(day: &AdventOfCodeDay) -> Result<Option<Vec<(Part, Check)>>, DataError> {
+    verify_day_with(DataDir::current(), day)
+}
+
+/// Same as [`verify_day`], but with the cache in `data`.
+///
+/// # Errors
+/// This function errors if the cached input can't be read.
+pub fn verify_day_with(
+    data: &DataDir,
+    day: &AdventOfCodeDay,
+) -> Result<Option<Vec<(Part, Check)>>, DataError> {
+    let Some(input) = read_cached_input(data, &SystemClock, day.year, day.day)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(verify(
+        day,
+        &input,
+        &KnownAnswers::load(data, day.year, day.day),
+    )))
+}
+
+/// Times `day` on its cached input, see [`benchmark`]. Returns `None` if the input
+/// isn't cached or the day isn't solved.
+///
+/// # Errors
+/// This function errors if the cached input can't be read.
+pub fn benchmark_day(
+    day: &AdventOfCodeDay,
+    settings: BenchSettings,
+) -> Result<Option<Benchmark>, DataError> {
+    let data = DataDir::current();
+
+    let start = Instant::now();
+    let Some(input) = read_cached_input(data, &SystemClock, day.year, day.day)? else {
+        return Ok(None);
+    };
+    let load = start.elapsed();
+
+    Ok(benchmark(day, &input, load, settings))
+}
+
+/// Checks the session key against Advent of Code, returning who it belongs to
+/// and where it was found.
+///
+/// # Errors
+/// This function errors if there's no session key or it isn't logged in.
+pub fn check_session() -> Result<(String, SessionSource), DataError> {
+    let session = find_session()?;
+    let client = AocClient::from_env(&session.token)?;
+
+    Ok((session::check_session(&client)?, session.source))
+}
+
+fn aoc_client() -> Result<AocClient, DataError> {
+    AocClient::from_env(&find_session()?.token)
+}
+
+/// Reads a cached input. Anything that doesn't look like one is quarantined and
+/// treated as not cached, so it gets fetched again.
+fn read_cached_input(
+    data: &DataDir,
+    clock: &dyn Clock,
+    year: u64,
+    day: u64,
+) -> Result<Option<String>, DataError> {
+    let path = data.input(year, day);
+
+    let Some(input) = read_cache_file(&path)? else {
+        return Ok(None);
+    };
+
+    if let Err(reason) = validate_input(&input) {
+        quarantine_cached(data, year, day, clock.now()).map_err(|e| {
+            DataError::InvalidContent(format!(
+                "{} is in the cache but {reason}, and couldn't be quarantined: {e}",
+                path.display()
+            ))
+        })?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(input))
+}
+
+/// Reads a cached file, with `None` meaning it hasn't been cached yet.
+fn read_cache_file(path: &Path) -> Result<Option<String>, DataError> {
+    match read_to_string(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => Err(DataError::InvalidContent(
+            format!("{} isn't valid UTF-8", path.display()),
+        )),
+        Err(e) => Err(DataError::cache_io(path, e)),
+    }
+}
+
+fn write_cache_file(path: &Path, data: &str) -> Result<(), DataError> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|e| DataError::cache_io(parent, e))?;
+    }
+
+    write(path, data).map_err(|e| DataError::cache_io(path, e))
 }
 
 #[test]
 fn should_cache() {
-    write_cache(0, 0, "test").unwrap();
-    assert_eq!(read_cache(0, 0).unwrap(), "test");
+    let data = DataDir::temporary("cache");
+
+    write_cache_file(&data.input(2019, 1), "test").unwrap();
+    assert_eq!(
+        read_cache_file(&data.input(2019, 1)).unwrap().as_deref(),
+        Some("test")
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_fetch_missing_input_once() {
+    use self::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.serve_input(2019, 1, "12\n14");
+
+    let data = DataDir::temporary("fetch_once");
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    assert_eq!(
+        get_data_with(&data, &client, &SystemClock, 2019, 1).unwrap(),
+        "12\n14"
+    );
+    assert_eq!(
+        get_data_with(&data, &client, &SystemClock, 2019, 1).unwrap(),
+        "12\n14"
+    );
+    assert_eq!(server.requests().len(), 1);
+
+    assert_eq!(
+        InputMeta::load(&data, 2019, 1).unwrap().session,
+        Some(cache::fingerprint("secret"))
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_refetch_quarantined_cached_inputs() {
+    use self::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.serve_input(2019, 1, "12\n14");
+
+    let data = DataDir::temporary("refetch_quarantined");
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    write_cache_file(&data.input(2019, 1), "<html><body>500</body></html>").unwrap();
+
+    assert_eq!(
+        get_data_with(&data, &client, &SystemClock, 2019, 1).unwrap(),
+        "12\n14"
+    );
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(
+        std::fs::read_dir(data.quarantine().join("2019"))
+            .unwrap()
+            .count(),
+        1
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_not_cache_logged_out_responses() {
+    use self::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/2019/day/1/input",
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    );
+
+    let data = DataDir::temporary("logged_out");
+    let client = AocClient::new(&server.url(), "expired").unwrap();
+
+    assert!(matches!(
+        get_data_with(&data, &client, &SystemClock, 2019, 1),
+        Err(DataError::ExpiredSession)
+    ));
+    assert!(!data.input(2019, 1).exists());
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_refuse_to_fetch_before_unlock() {
+    use self::{mock_server::MockServer, unlock::ManualClock};
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    server.serve_input(2019, 1, "early");
+
+    let data = DataDir::temporary("before_unlock");
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+    let clock = ManualClock::new(unlock::unlock_time(2019, 1) - Duration::from_secs(1));
+
+    assert!(matches!(
+        get_data_with(&data, &client, &clock, 2019, 1),
+        Err(DataError::NotUnlocked { year: 2019, day: 1 })
+    ));
+    assert!(server.requests().is_empty());
+
+    unlock::wait_for_unlock(&clock, 2019, 1, |_| ());
+
+    assert_eq!(
+        get_data_with(&data, &client, &clock, 2019, 1).unwrap(),
+        "early"
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_quarantine_invalid_inputs_instead_of_caching() {
+    use self::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.serve_input(
+        2019,
+        1,
+        "<!DOCTYPE html><html><body>Internal Server Error</body></html>",
+    );
+
+    let data = DataDir::temporary("quarantine");
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    assert!(matches!(
+        get_data_with(&data, &client, &SystemClock, 2019, 1),
+        Err(DataError::InvalidContent(_))
+    ));
+    assert!(!data.input(2019, 1).exists());
+    assert_eq!(
+        std::fs::read_dir(data.quarantine().join("2019"))
+            .unwrap()
+            .count(),
+        1
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_sync_earned_stars_and_answers() {
+    use self::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/2019",
+        200,
+        r#"<a aria-label="Day 1, two stars" href="/2019/day/1">1</a><a aria-label="Day 2, one star" href="/2019/day/2">2</a><a aria-label="Day 3" href="/2019/day/3">3</a>"#,
+    );
+    server.respond(
+        "/2019/day/2",
+        200,
+        "<p>Your puzzle answer was <code>3101844</code>.</p>",
+    );
+
+    let data = DataDir::temporary("earned");
+    write_cache_file(
+        &data.puzzle(2019, 1),
+        "<p>Your puzzle answer was <code>3267638</code>.</p><p>Your puzzle answer was <code>4898585</code>.</p>",
+    )
+    .unwrap();
+
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+    let earned = sync_earned_with(&data, &client, 2019).unwrap();
+
+    assert_eq!(earned.stars(), 3);
+    assert_eq!(earned.day(1).answers, vec!["3267638", "4898585"]);
+    assert_eq!(earned.day(2).answers, vec!["3101844"]);
+    assert_eq!(earned.day(3), EarnedDay::default());
+    assert_eq!(EarnedYear::load(&data, 2019), earned);
+    assert_eq!(
+        KnownAnswers::load(&data, 2019, 1).get(Part::Two),
+        Some("4898585")
+    );
+    assert!(KnownAnswers::load(&data, 2019, 3).is_empty());
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .collect();
+    assert_eq!(paths, vec!["/2019", "/2019/day/2"]);
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_cache_leaderboard_for_fifteen_minutes() {
+    use self::{mock_server::MockServer, unlock::ManualClock};
+    use std::time::SystemTime;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/2019/leaderboard/private/view/101.json",
+        200,
+        include_str!("solves/fixtures/leaderboard_2019.json"),
+    );
+
+    let data = DataDir::temporary("leaderboard");
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    let clock = ManualClock::new(SystemTime::now());
+    let (leaderboard, age) = get_leaderboard_with(&data, &client, &clock, 2019, 101).unwrap();
+    assert_eq!(leaderboard.members.len(), 3);
+    assert_eq!(age, Duration::ZERO);
+
+    clock.sleep(Duration::from_secs(10 * 60));
+    let (_, age) = get_leaderboard_with(&data, &client, &clock, 2019, 101).unwrap();
+    assert!(age >= Duration::from_secs(10 * 60));
+    assert_eq!(server.requests().len(), 1);
+
+    clock.sleep(Duration::from_secs(6 * 60));
+    get_leaderboard_with(&data, &client, &clock, 2019, 101).unwrap();
+    assert_eq!(server.requests().len(), 2);
+
+    std::fs::remove_dir_all(data.root()).unwrap();
 }
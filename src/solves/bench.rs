@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+
+use super::{
+    solution::SolveError,
+    year::{AdventOfCodeDay, Part},
+};
+
+/// How many untimed runs warm up caches and the allocator before the timed ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchSettings {
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for BenchSettings {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            iterations: 10,
+        }
+    }
+}
+
+/// A summary of repeated timings, with the population standard deviation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// `None` without samples.
+    #[must_use]
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let count = u32::try_from(samples.len())
+            .ok()
+            .filter(|count| *count > 0)?;
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / count;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / f64::from(count);
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+
+    /// Stats of running everything in `stats` one after the other, taking the runs as
+    /// independent so their variances add up.
+    #[must_use]
+    pub fn total<'a>(stats: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut total = Self::default();
+        let mut variance = 0.0;
+
+        for stats in stats {
+            total.min += stats.min;
+            total.median += stats.median;
+            total.mean += stats.mean;
+            variance += stats.stddev.as_secs_f64().powi(2);
+        }
+
+        total.stddev = Duration::from_secs_f64(f64::sqrt(variance));
+        total
+    }
+}
+
+/// Timings of a day, with loading, parsing and solving kept apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    /// Reading the input, which is only done once.
+    pub load: Duration,
+    /// The parts aren't timed if parsing fails.
+    pub parse: Result<Stats, SolveError>,
+    pub parts: Vec<(Part, Result<Stats, SolveError>)>,
+}
+
+/// Runs `f` `settings.iterations` times after warming up, stopping at the first error.
+fn time<T>(
+    settings: BenchSettings,
+    mut f: impl FnMut() -> Result<T, SolveError>,
+) -> Result<(T, Stats), SolveError> {
+    let mut result = None;
+
+    for _ in 0..settings.warmup {
+        result = Some(f()?);
+    }
+
+    let mut samples = vec![];
+
+    for _ in 0..settings.iterations.max(1) {
+        let start = Instant::now();
+        result = Some(f()?);
+        samples.push(start.elapsed());
+    }
+
+    let stats = Stats::new(&samples).unwrap_or_default();
+
+    Ok((result.expect("Should have run at least once"), stats))
+}
+
+/// Times parsing `input` and solving each part of `day` from the parsed input.
+/// `None` if the day isn't solved at all.
+///
+/// # Panics
+/// If `day` can't parse or solve a part it claims to have, which can't happen for
+/// days built with [`AdventOfCodeDay`]'s constructors.
+#[must_use]
+pub fn benchmark(
+    day: &AdventOfCodeDay,
+    input: &str,
+    load: Duration,
+    settings: BenchSettings,
+) -> Option<Benchmark> {
+    if day.parts().is_empty() {
+        return None;
+    }
+
+    let parsed = time(settings, || day.parse(input).expect("Day should be solved"));
+
+    let (parsed, parse) = match parsed {
+        Ok((parsed, stats)) => (parsed, Ok(stats)),
+        Err(e) => {
+            return Some(Benchmark {
+                load,
+                parse: Err(e),
+                parts: vec![],
+            })
+        }
+    };
+
+    let parts = day
+        .parts()
+        .into_iter()
+        .map(|part| {
+            let stats = time(settings, || {
+                day.solve(&parsed, part).expect("Part should be solved")
+            });
+
+            (part, stats.map(|(_, stats)| stats))
+        })
+        .collect();
+
+    Some(Benchmark { load, parse, parts })
+}
+
+#[test]
+fn should_summarize_timings() {
+    let millis = |samples: &[u64]| -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    };
+
+    let stats = Stats::new(&millis(&[4, 2, 9, 4, 5, 7, 5, 4])).unwrap();
+
+    assert_eq!(stats.min, Duration::from_millis(2));
+    assert_eq!(stats.median, Duration::from_millis(9) / 2);
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    assert!((stats.stddev.as_secs_f64() - 0.002).abs() < 1e-9);
+
+    assert_eq!(Stats::new(&[]), None);
+
+    let total = Stats::total([&stats, &Stats::new(&millis(&[1, 3])).unwrap()]);
+
+    assert_eq!(total.min, Duration::from_millis(3));
+    assert_eq!(total.mean, Duration::from_millis(7));
+    assert!((total.stddev.as_secs_f64() - f64::sqrt(5.0) / 1000.0).abs() < 1e-9);
+}
@@ -0,0 +1,397 @@
+use std::{
+    fs::{create_dir_all, read, read_dir, read_to_string, remove_file, write},
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::{catalog::days_in_event, data_dir::DataDir};
+
+/// When an input was fetched and with which session, stored next to it in `day{day}.meta`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMeta {
+    pub fetched: SystemTime,
+    pub session: Option<String>,
+}
+
+impl InputMeta {
+    /// Lines look like `fetched\t{unix seconds}` and `session\t{fingerprint}`.
+    #[must_use]
+    pub fn parse(data: &str) -> Option<Self> {
+        let mut fetched = None;
+        let mut session = None;
+
+        for line in data.lines() {
+            match line.split_once('\t') {
+                Some(("fetched", seconds)) => {
+                    fetched = Some(UNIX_EPOCH + Duration::from_secs(seconds.parse().ok()?));
+                }
+                Some(("session", fingerprint)) => session = Some(fingerprint.to_string()),
+                _ => (),
+            }
+        }
+
+        Some(Self {
+            fetched: fetched?,
+            session,
+        })
+    }
+
+    #[must_use]
+    pub fn serialize(&self) -> String {
+        let seconds = self
+            .fetched
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        self.session.as_ref().map_or_else(
+            || format!("fetched\t{seconds}\n"),
+            |session| format!("fetched\t{seconds}\nsession\t{session}\n"),
+        )
+    }
+
+    #[must_use]
+    pub fn load(data: &DataDir, year: u64, day: u64) -> Option<Self> {
+        Self::parse(&read_to_string(data.input_meta(year, day)).ok()?)
+    }
+
+    /// # Errors
+    /// This function errors if the metadata can't be written.
+    pub fn save(&self, data: &DataDir, year: u64, day: u64) -> Result<(), io::Error> {
+        write(data.input_meta(year, day), self.serialize())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedInput {
+    pub year: u64,
+    pub day: u64,
+    pub size: u64,
+    pub hash: u64,
+    pub meta: Option<InputMeta>,
+    /// Why the input doesn't look like one, see [`validate_input`].
+    pub problem: Option<String>,
+}
+
+/// Error pages that come back with a successful status still shouldn't end up as inputs.
+///
+/// # Errors
+/// This function errors with the reason `input` doesn't look like a puzzle input.
+pub fn validate_input(input: &str) -> Result<(), String> {
+    let trimmed = input.trim_start().to_lowercase();
+
+    if trimmed.is_empty() {
+        return Err(String::from("it's empty"));
+    }
+
+    if trimmed.starts_with("<!doctype") || trimmed.starts_with("<html") || trimmed.contains("<body")
+    {
+        return Err(String::from("it's an HTML page"));
+    }
+
+    if trimmed.contains("before it unlocks") || trimmed.contains("please log in") {
+        return Err(String::from("it's an error message from Advent of Code"));
+    }
+
+    Ok(())
+}
+
+/// Keeps `content` in `quarantine/{year}/day{day}-{unix seconds}.txt`, returning where it went.
+///
+/// # Errors
+/// This function errors if the content can't be written.
+pub fn quarantine(
+    data: &DataDir,
+    year: u64,
+    day: u64,
+    content: &str,
+    at: SystemTime,
+) -> Result<PathBuf, io::Error> {
+    let seconds = at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+    let directory = data.quarantine().join(year.to_string());
+    create_dir_all(&directory)?;
+
+    let path = directory.join(format!("day{day}-{seconds}.txt"));
+    write(&path, content)?;
+
+    Ok(path)
+}
+
+/// Moves a cached input to quarantine, so it gets fetched again next time.
+///
+/// # Errors
+/// This function errors if the input can't be read, quarantined or removed.
+pub fn quarantine_cached(
+    data: &DataDir,
+    year: u64,
+    day: u64,
+    at: SystemTime,
+) -> Result<PathBuf, io::Error> {
+    let input = read(data.input(year, day))?;
+    let path = quarantine(data, year, day, &String::from_utf8_lossy(&input), at)?;
+
+    for path in [data.input(year, day), data.input_meta(year, day)] {
+        match remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => (),
+        }
+    }
+
+    Ok(path)
+}
+
+/// 64 bit FNV-1a, enough to tell inputs apart at a glance.
+#[must_use]
+pub const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+
+    hash
+}
+
+/// A short, stable name for a session key that doesn't give the key away.
+#[must_use]
+pub fn fingerprint(session: &str) -> String {
+    format!("{:08x}", fnv1a(session.as_bytes()) >> 32)
+}
+
+/// Names like `2019` or `day1.txt` hold a number between `prefix` and `suffix`.
+fn number_in(path: &Path, prefix: &str, suffix: &str) -> Option<u64> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Every cached input, sorted by year and day.
+///
+/// # Errors
+/// This function errors if the cache exists but can't be read.
+pub fn list(data: &DataDir) -> Result<Vec<CachedInput>, io::Error> {
+    let years = match read_dir(data.cache()) {
+        Ok(years) => years,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in years {
+        let Some(year) = number_in(&entry?.path(), "", "") else {
+            continue;
+        };
+
+        for entry in read_dir(data.cache_year(year))? {
+            let Some(day) = number_in(&entry?.path(), "day", ".txt") else {
+                continue;
+            };
+
+            let input = read(data.input(year, day))?;
+
+            inputs.push(CachedInput {
+                year,
+                day,
+                size: input.len() as u64,
+                hash: fnv1a(&input),
+                meta: InputMeta::load(data, year, day),
+                problem: validate_input(&String::from_utf8_lossy(&input)).err(),
+            });
+        }
+    }
+
+    inputs.sort_by_key(|input| (input.year, input.day));
+
+    Ok(inputs)
+}
+
+/// Cached inputs that don't look like inputs, e.g. error pages cached by older versions.
+///
+/// # Errors
+/// This function errors if the cache exists but can't be read.
+pub fn check_integrity(data: &DataDir) -> Result<Vec<CachedInput>, io::Error> {
+    Ok(list(data)?
+        .into_iter()
+        .filter(|input| input.problem.is_some())
+        .collect())
+}
+
+/// Removes the input, its metadata and the puzzle page for a day.
+///
+/// # Errors
+/// This function errors if any of them exist but can't be removed.
+pub fn delete_day(data: &DataDir, year: u64, day: u64) -> Result<(), io::Error> {
+    for path in [
+        data.input(year, day),
+        data.input_meta(year, day),
+        data.puzzle(year, day),
+    ] {
+        match remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether any input of the year is cached.
+#[must_use]
+pub fn has_year(data: &DataDir, year: u64) -> bool {
+    (1..=days_in_event(year)).any(|day| data.input(year, day).exists())
+}
+
+/// Removes what [`delete_day`] does for every day of the year, and the calendar.
+/// Known answers, earned stars and leaderboards are kept, they can't always be fetched again.
+///
+/// # Errors
+/// This function errors if any of them exist but can't be removed.
+pub fn delete_year(data: &DataDir, year: u64) -> Result<(), io::Error> {
+    for day in 1..=days_in_event(year) {
+        delete_day(data, year, day)?;
+    }
+
+    match remove_file(data.calendar(year)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn should_hash_like_reference_fnv1a() {
+    assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+}
+
+#[test]
+fn should_roundtrip_meta() {
+    let meta = InputMeta {
+        fetched: UNIX_EPOCH + Duration::from_secs(1_575_176_412),
+        session: Some(fingerprint("secret")),
+    };
+
+    assert_eq!(InputMeta::parse(&meta.serialize()), Some(meta));
+    assert_eq!(InputMeta::parse("session\tabc"), None);
+}
+
+#[test]
+fn should_list_and_delete_cached_inputs() {
+    let data = DataDir::temporary("cache_listing");
+
+    for (year, day, input) in [(2019, 2, "1,0,0,3"), (2019, 1, "12"), (2020, 1, "1721")] {
+        std::fs::create_dir_all(data.cache_year(year)).unwrap();
+        write(data.input(year, day), input).unwrap();
+    }
+
+    write(data.puzzle(2019, 1), "<main></main>").unwrap();
+    InputMeta {
+        fetched: UNIX_EPOCH,
+        session: None,
+    }
+    .save(&data, 2019, 1)
+    .unwrap();
+
+    let inputs = list(&data).unwrap();
+    assert_eq!(
+        inputs
+            .iter()
+            .map(|input| (input.year, input.day, input.size))
+            .collect::<Vec<_>>(),
+        vec![(2019, 1, 2), (2019, 2, 7), (2020, 1, 4)]
+    );
+    assert_eq!(inputs[0].hash, fnv1a(b"12"));
+    assert!(inputs[0].meta.is_some());
+    assert!(inputs[1].meta.is_none());
+
+    delete_day(&data, 2019, 1).unwrap();
+    assert!(!data.puzzle(2019, 1).exists());
+    assert!(!data.input_meta(2019, 1).exists());
+
+    assert!(has_year(&data, 2020));
+    delete_year(&data, 2020).unwrap();
+    assert!(!has_year(&data, 2020));
+    assert_eq!(
+        list(&data)
+            .unwrap()
+            .iter()
+            .map(|input| (input.year, input.day))
+            .collect::<Vec<_>>(),
+        vec![(2019, 2)]
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_keep_known_answers_when_deleting_a_year() {
+    use super::{known::KnownAnswers, year::Part};
+
+    let data = DataDir::temporary("cache_keep_known");
+
+    let mut known = KnownAnswers::default();
+    known.set(Part::One, "3394032");
+    known.save(&data, 2019, 1).unwrap();
+
+    write(data.input(2019, 1), "12").unwrap();
+    write(data.calendar(2019), "<main></main>").unwrap();
+
+    delete_year(&data, 2019).unwrap();
+
+    assert!(!data.input(2019, 1).exists());
+    assert!(!data.calendar(2019).exists());
+    assert_eq!(KnownAnswers::load(&data, 2019, 1), known);
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_flag_inputs_that_are_not_inputs() {
+    assert!(validate_input("1,0,0,3,99\n").is_ok());
+    assert!(validate_input("<x=-1, y=0, z=2>\n").is_ok());
+
+    assert!(validate_input("").is_err());
+    assert!(validate_input(" \n").is_err());
+    assert!(validate_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+    assert!(validate_input(
+        "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available."
+    )
+    .is_err());
+}
+
+#[test]
+fn should_quarantine_suspicious_cached_inputs() {
+    let data = DataDir::temporary("integrity");
+
+    std::fs::create_dir_all(data.cache_year(2019)).unwrap();
+    write(data.input(2019, 1), "12\n14").unwrap();
+    write(data.input(2019, 2), "<html><body>500</body></html>").unwrap();
+    write(data.input(2019, 3), "").unwrap();
+
+    let flagged = check_integrity(&data).unwrap();
+    assert_eq!(
+        flagged.iter().map(|input| input.day).collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+
+    let at = UNIX_EPOCH + Duration::from_secs(60);
+    let path = quarantine_cached(&data, 2019, 2, at).unwrap();
+
+    assert_eq!(path, data.quarantine().join("2019/day2-60.txt"));
+    assert_eq!(
+        read_to_string(path).unwrap(),
+        "<html><body>500</body></html>"
+    );
+    assert!(!data.input(2019, 2).exists());
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
@@ -0,0 +1,224 @@
+use std::{ops::RangeInclusive, time::SystemTime};
+
+use super::{
+    unlock::{eastern_date, is_unlocked, unlock_time, Clock},
+    year::{AdventOfCodeDay, AdventOfCodeYear, DayProgress},
+};
+
+/// The first Advent of Code.
+pub const FIRST_EVENT: u64 = 2015;
+
+/// Events ran for 25 days until 2024, and for 12 days from 2025 on.
+#[must_use]
+pub const fn days_in_event(year: u64) -> u64 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+#[must_use]
+pub const fn is_event_day(year: u64, day: u64) -> bool {
+    year >= FIRST_EVENT && day >= 1 && day <= days_in_event(year)
+}
+
+/// The most recent event that has started, the current one during December.
+#[must_use]
+pub fn latest_event(clock: &dyn Clock) -> u64 {
+    let (year, month, _) = eastern_date(clock);
+
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Every event that has started, oldest first.
+#[must_use]
+pub fn event_years(clock: &dyn Clock) -> RangeInclusive<u64> {
+    FIRST_EVENT..=latest_event(clock)
+}
+
+/// The most recently unlocked puzzle, as (year, day).
+#[must_use]
+pub fn latest_unlocked(clock: &dyn Clock) -> (u64, u64) {
+    let year = latest_event(clock);
+
+    let day = (1..=days_in_event(year))
+        .rev()
+        .find(|day| is_unlocked(clock, year, *day))
+        .unwrap_or(1);
+
+    (year, day)
+}
+
+/// Where the "Latest" shortcuts go among `years`, as (year, day).
+///
+/// During December that's today's puzzle, solved or not. The rest of the year it's
+/// whichever comes later of the partly solved day furthest along and the day after
+/// the last fully solved one. `None` if `years` is empty.
+#[must_use]
+pub fn latest_day(clock: &dyn Clock, years: &[AdventOfCodeYear]) -> Option<(u64, u64)> {
+    let (today, month, _) = eastern_date(clock);
+
+    if month == 12 && years.iter().any(|year| year.year == today) {
+        return Some(latest_unlocked(clock));
+    }
+
+    let slots: Vec<(u64, u64, DayProgress)> = years
+        .iter()
+        .flat_map(|year| {
+            year.calendar().map(|(day, solution)| {
+                let progress = solution.map_or(DayProgress::Unsolved, AdventOfCodeDay::progress);
+
+                (year.year, day, progress)
+            })
+        })
+        .collect();
+
+    if slots.is_empty() {
+        return None;
+    }
+
+    let latest_partly = slots
+        .iter()
+        .rposition(|(_, _, progress)| matches!(progress, DayProgress::PartlySolved));
+
+    let after_fully = slots
+        .iter()
+        .rposition(|(_, _, progress)| matches!(progress, DayProgress::FullySolved))
+        .map(|idx| (idx + 1).min(slots.len() - 1));
+
+    let idx = match (latest_partly, after_fully) {
+        (Some(partly), Some(fully)) => partly.max(fully),
+        (Some(idx), None) | (None, Some(idx)) => idx,
+        (None, None) => slots.iter().rposition(|(_, day, _)| *day == 1)?,
+    };
+
+    slots.get(idx).map(|(year, day, _)| (*year, *day))
+}
+
+/// A slot on an event's calendar, whether or not it has a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventDay {
+    pub year: u64,
+    pub day: u64,
+    pub unlocks: SystemTime,
+    /// Known once the day is solved or its puzzle page has been cached.
+    pub title: Option<String>,
+}
+
+/// Every day of an event, titled by `title` where it knows the puzzle.
+#[must_use]
+pub fn event_days(year: u64, title: impl Fn(u64) -> Option<String>) -> Vec<EventDay> {
+    (1..=days_in_event(year))
+        .map(|day| EventDay {
+            year,
+            day,
+            unlocks: unlock_time(year, day),
+            title: title(day),
+        })
+        .collect()
+}
+
+#[test]
+fn should_know_every_event() {
+    use super::unlock::ManualClock;
+    use std::time::Duration;
+
+    assert_eq!(days_in_event(2015), 25);
+    assert_eq!(days_in_event(2024), 25);
+    assert_eq!(days_in_event(2025), 12);
+    assert!(is_event_day(2025, 12));
+    assert!(!is_event_day(2025, 13));
+    assert!(!is_event_day(2014, 1));
+
+    let november = ManualClock::new(unlock_time(2026, 1) - Duration::from_secs(60));
+    assert_eq!(event_years(&november), 2015..=2025);
+    assert_eq!(latest_unlocked(&november), (2025, 12));
+
+    let december = ManualClock::new(unlock_time(2026, 3) + Duration::from_secs(60));
+    assert_eq!(event_years(&december), 2015..=2026);
+    assert_eq!(latest_unlocked(&december), (2026, 3));
+
+    let days = event_days(2025, |day| {
+        (day == 1).then(|| String::from("Secret Entrance"))
+    });
+    assert_eq!(days.len(), 12);
+    assert_eq!(days[0].title.as_deref(), Some("Secret Entrance"));
+    assert_eq!(days[11].unlocks, unlock_time(2025, 12));
+    assert_eq!(days[11].title, None);
+}
+
+#[test]
+fn should_find_the_latest_day() {
+    use super::{
+        solution::{Answer, Solution, SolveError},
+        unlock::ManualClock,
+        year::AdventOfCodeDay,
+    };
+    use std::time::Duration;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Parsed = String;
+
+        const YEAR: u64 = 2019;
+        const DAY: u64 = 2;
+
+        fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.as_str().into())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.as_str().into())
+        }
+    }
+
+    struct Finale;
+
+    impl Solution for Finale {
+        type Parsed = String;
+
+        const YEAR: u64 = 2025;
+        const DAY: u64 = 12;
+
+        fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+            Echo::parse(input)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Echo::part1(parsed)
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Echo::part2(parsed)
+        }
+    }
+
+    let october = ManualClock::new(unlock_time(2026, 1) - Duration::from_secs(45 * 24 * 60 * 60));
+    let december = ManualClock::new(unlock_time(2026, 5) + Duration::from_secs(60));
+
+    let empty = || AdventOfCodeYear::new(2026, vec![]);
+    let solved = || AdventOfCodeYear::new(2019, vec![AdventOfCodeDay::new::<Echo>("Echo")]);
+    let partly = || AdventOfCodeYear::new(2019, vec![AdventOfCodeDay::part1_only::<Echo>("Echo")]);
+
+    assert_eq!(latest_day(&december, &[solved(), empty()]), Some((2026, 5)));
+    assert_eq!(latest_day(&december, &[solved()]), Some((2019, 3)));
+
+    assert_eq!(latest_day(&october, &[solved()]), Some((2019, 3)));
+    assert_eq!(latest_day(&october, &[partly()]), Some((2019, 2)));
+    assert_eq!(latest_day(&october, &[empty()]), Some((2026, 1)));
+    assert_eq!(latest_day(&october, &[]), None);
+    assert_eq!(latest_day(&december, &[]), None);
+
+    let finished = AdventOfCodeYear::new(2025, vec![AdventOfCodeDay::new::<Finale>("Finale")]);
+    assert_eq!(latest_day(&october, &[finished]), Some((2025, 12)));
+}
@@ -0,0 +1,263 @@
+use std::{env, sync::OnceLock, time::Duration};
+
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{COOKIE, USER_AGENT},
+};
+
+use super::{
+    cache::fingerprint,
+    data_dir::DataDir,
+    error::DataError,
+    provider::InputProvider,
+    session::is_logged_out,
+    throttle::{RequestLog, Throttle, DEFAULT_MIN_INTERVAL},
+    unlock::SystemClock,
+};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str = "github.com/pehdfms/rusty-xmas (contact not configured)";
+
+/// HTTP client for Advent of Code, or anything that answers like it.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    client: Client,
+    throttle: Option<Throttle>,
+    log: Option<RequestLog>,
+}
+
+/// Every `AocClient` shares one connection pool instead of building its own.
+fn shared_client() -> Result<Client, DataError> {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+
+    if let Some(client) = CLIENT.get() {
+        return Ok(client.clone());
+    }
+
+    let client = Client::builder().build()?;
+
+    Ok(CLIENT.get_or_init(|| client).clone())
+}
+
+impl AocClient {
+    /// A client without throttling or logging, mostly useful against local servers.
+    ///
+    /// # Errors
+    /// This function errors if the underlying HTTP client can't be built.
+    pub fn new(base_url: &str, session: &str) -> Result<Self, DataError> {
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            client: shared_client()?,
+            throttle: None,
+            log: None,
+        })
+    }
+
+    /// The client used for the real website. It reads `AOC_BASE_URL`, `AOC_USER_AGENT`
+    /// and `AOC_MIN_INTERVAL` (in seconds), throttles through `throttle.txt` and
+    /// logs every request to `requests.log` in the data directory.
+    ///
+    /// # Errors
+    /// This function errors if the underlying HTTP client can't be built.
+    pub fn from_env(session: &str) -> Result<Self, DataError> {
+        let interval = env::var("AOC_MIN_INTERVAL")
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .map_or(DEFAULT_MIN_INTERVAL, Duration::from_secs);
+
+        let data = DataDir::current();
+
+        let mut client = Self::new(&Self::base_url_from_env(), session)?
+            .with_throttle(Throttle::new(
+                data.throttle_file(),
+                interval,
+                Box::new(SystemClock),
+            ))
+            .with_log(RequestLog::new(data.request_log(), Box::new(SystemClock)));
+
+        if let Ok(user_agent) = env::var("AOC_USER_AGENT") {
+            client.user_agent = user_agent;
+        }
+
+        Ok(client)
+    }
+
+    #[must_use]
+    pub fn with_user_agent(self, user_agent: &str) -> Self {
+        Self {
+            user_agent: user_agent.to_string(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_throttle(self, throttle: Throttle) -> Self {
+        Self {
+            throttle: Some(throttle),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_log(self, log: RequestLog) -> Self {
+        Self {
+            log: Some(log),
+            ..self
+        }
+    }
+
+    /// Reads the base URL from `AOC_BASE_URL`, falling back to the real website.
+    #[must_use]
+    pub fn base_url_from_env() -> String {
+        env::var("AOC_BASE_URL").unwrap_or_else(|_| AOC_URL.to_string())
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    #[must_use]
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// # Errors
+    /// This function errors if the request fails or doesn't come back successful.
+    pub fn get(&self, path: &str) -> Result<String, DataError> {
+        let (status, data) = self.get_with_status(path)?;
+
+        if !(200..300).contains(&status) {
+            return Err(DataError::HttpStatus {
+                status,
+                url: self.url(path),
+            });
+        }
+
+        Ok(data)
+    }
+
+    /// # Errors
+    /// This function errors if the request can't be sent or its body can't be read.
+    pub fn get_with_status(&self, path: &str) -> Result<(u16, String), DataError> {
+        self.send(self.client.get(self.url(path)))
+    }
+
+    /// # Errors
+    /// This function errors if the request can't be sent or its body can't be read.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<(u16, String), DataError> {
+        self.send(self.client.post(self.url(path)).form(form))
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<(u16, String), DataError> {
+        let request = request
+            .header(COOKIE, format!("session={}", self.session))
+            .header(USER_AGENT, &self.user_agent)
+            .build()?;
+
+        if let Some(throttle) = &self.throttle {
+            throttle
+                .wait()
+                .map_err(|e| DataError::cache_io(throttle.path(), e))?;
+        }
+
+        let method = request.method().to_string();
+        let url = request.url().to_string();
+
+        let response = self.client.execute(request);
+
+        if let Some(log) = &self.log {
+            let status = response
+                .as_ref()
+                .ok()
+                .map(|response| response.status().as_u16());
+
+            let _unused_result = log.record(&method, &url, status).inspect_err(|_| {
+                println!("Couldn't write to request log!");
+            });
+        }
+
+        let response = response?;
+        let status = response.status().as_u16();
+
+        Ok((status, response.text()?))
+    }
+}
+
+impl InputProvider for AocClient {
+    fn input(&self, year: u64, day: u64) -> Result<String, DataError> {
+        let path = format!("/{year}/day/{day}/input");
+        let (status, data) = self.get_with_status(&path)?;
+
+        if is_logged_out(status, &data) {
+            return Err(DataError::ExpiredSession);
+        }
+
+        if status == 404 && data.contains("before it unlocks") {
+            return Err(DataError::NotUnlocked { year, day });
+        }
+
+        if !(200..300).contains(&status) {
+            return Err(DataError::HttpStatus {
+                status,
+                url: self.url(&path),
+            });
+        }
+
+        Ok(data)
+    }
+
+    fn session_fingerprint(&self) -> Option<String> {
+        Some(fingerprint(&self.session))
+    }
+}
+
+#[test]
+fn should_request_input_with_session() {
+    use super::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.serve_input(2019, 1, "12\n14");
+
+    let client = AocClient::new(&format!("{}/", server.url()), "secret")
+        .unwrap()
+        .with_user_agent("rusty-xmas tests");
+
+    assert_eq!(client.input(2019, 1).unwrap(), "12\n14");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2019/day/1/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    assert_eq!(requests[0].header("user-agent"), Some("rusty-xmas tests"));
+}
+
+#[test]
+fn should_map_error_statuses() {
+    use super::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/2019/day/2/input",
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.",
+    );
+    server.respond("/2019/day/3/input", 500, "Internal Server Error");
+
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    assert!(matches!(
+        client.input(2019, 2),
+        Err(DataError::NotUnlocked { year: 2019, day: 2 })
+    ));
+
+    assert!(matches!(
+        client.input(2019, 3),
+        Err(DataError::HttpStatus { status: 500, .. })
+    ));
+}
@@ -0,0 +1,101 @@
+use std::{
+    fs::{read_dir, read_to_string},
+    io,
+};
+
+use super::{data_dir::DataDir, year::Part};
+
+/// An alternative input for a day, e.g. a much larger one to stress a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomInput {
+    pub name: String,
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl CustomInput {
+    #[must_use]
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers[usize::from(part.number() - 1)].as_deref()
+    }
+}
+
+const ANSWER_SUFFIXES: [&str; 2] = ["_part1_answer", "_part2_answer"];
+
+/// Loads every `{name}.txt` in `custom/{year}/day{day}`, along with the expected
+/// answers in `{name}_part1_answer.txt` and `{name}_part2_answer.txt` when they exist.
+///
+/// # Errors
+/// This function errors if the directory exists but an input can't be read.
+pub fn load(data: &DataDir, year: u64, day: u64) -> Result<Vec<CustomInput>, io::Error> {
+    let directory = data.custom(year, day);
+
+    let entries = match read_dir(&directory) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in entries {
+        let file_name = entry?.file_name();
+
+        let Some(name) = file_name
+            .to_str()
+            .and_then(|name| name.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+
+        if ANSWER_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+            continue;
+        }
+
+        let answers = ANSWER_SUFFIXES.map(|suffix| {
+            read_to_string(directory.join(format!("{name}{suffix}.txt")))
+                .ok()
+                .map(|answer| answer.trim().to_string())
+        });
+
+        inputs.push(CustomInput {
+            name: name.to_string(),
+            input: read_to_string(directory.join(&file_name))?,
+            answers,
+        });
+    }
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(inputs)
+}
+
+#[test]
+fn should_load_inputs_with_optional_answers() {
+    let data = DataDir::temporary("custom_inputs");
+    let directory = data.custom(2019, 1);
+    std::fs::create_dir_all(&directory).unwrap();
+
+    std::fs::write(directory.join("bigboy.txt"), "100756\n").unwrap();
+    std::fs::write(directory.join("bigboy_part1_answer.txt"), "33583\n").unwrap();
+    std::fs::write(directory.join("alice.txt"), "12\n").unwrap();
+    std::fs::write(directory.join("notes.md"), "not an input").unwrap();
+
+    let inputs = load(&data, 2019, 1).unwrap();
+
+    assert_eq!(
+        inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["alice", "bigboy"]
+    );
+    assert_eq!(inputs[1].input, "100756\n");
+    assert_eq!(inputs[1].answer(Part::One), Some("33583"));
+    assert_eq!(inputs[1].answer(Part::Two), None);
+    assert_eq!(inputs[0].answer(Part::One), None);
+
+    assert!(load(&data, 2019, 2).unwrap().is_empty());
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
@@ -0,0 +1,317 @@
+use std::{
+    env,
+    fs::{read_dir, read_to_string},
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use super::session::config_dir;
+
+pub const DATA_ROOT_VAR: &str = "AOC_DATA_ROOT";
+pub const DATA_ROOT_CONFIG_FILE: &str = "data_root.txt";
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+
+/// The profile that uses the session lookup and cache layout from before profiles existed.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Where the data lives when nothing else is configured, relative to the working directory.
+pub const DEFAULT_DATA_ROOT: &str = "data";
+
+static CURRENT: OnceLock<DataDir> = OnceLock::new();
+
+/// Everything that isn't code: cached inputs and puzzles, answer ledgers,
+/// example fixtures, the session key and the request throttle.
+///
+/// Named profiles have their own session and answer ledgers in `profiles/{profile}`
+/// and their own cache in `cache/{profile}`, everything else is shared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataDir {
+    root: PathBuf,
+    profile: Option<String>,
+}
+
+/// Profile names share `cache/` with years, so they can't be numbers.
+#[must_use]
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != DEFAULT_PROFILE
+        && !name.chars().all(|c| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+impl DataDir {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            profile: None,
+        }
+    }
+
+    /// Uses `$AOC_DATA_ROOT`, then the path stored in the config file `data_root.txt`,
+    /// then `data` in the working directory. The profile comes from `$AOC_PROFILE`.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let config_file = config_dir().map(|directory| directory.join(DATA_ROOT_CONFIG_FILE));
+        let profile = env::var(PROFILE_VAR).ok();
+
+        Self::resolve(env::var_os(DATA_ROOT_VAR).map(PathBuf::from), config_file).with_profile(
+            profile
+                .as_deref()
+                .filter(|name| is_valid_profile_name(name)),
+        )
+    }
+
+    #[must_use]
+    pub fn resolve(variable: Option<PathBuf>, config_file: Option<PathBuf>) -> Self {
+        let root = variable
+            .filter(|root| !root.as_os_str().is_empty())
+            .or_else(|| {
+                let root = read_to_string(config_file?).ok()?.trim().to_string();
+
+                (!root.is_empty()).then(|| PathBuf::from(root))
+            })
+            .unwrap_or_else(|| {
+                env::current_dir()
+                    .unwrap_or_default()
+                    .join(DEFAULT_DATA_ROOT)
+            });
+
+        Self::new(root)
+    }
+
+    #[must_use]
+    pub fn with_root(self, root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            ..self
+        }
+    }
+
+    /// The same data directory seen from another profile, `None` being the default one.
+    #[must_use]
+    pub fn with_profile(self, profile: Option<&str>) -> Self {
+        Self {
+            profile: profile.map(str::to_string),
+            ..self
+        }
+    }
+
+    /// Makes this the data directory for the rest of the run, e.g. from `--data-root`.
+    /// Returns `false` if one has already been chosen.
+    pub fn install(self) -> bool {
+        CURRENT.set(self).is_ok()
+    }
+
+    /// The installed data directory, falling back to [`DataDir::from_env`].
+    pub fn current() -> &'static Self {
+        CURRENT.get_or_init(Self::from_env)
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    #[must_use]
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    #[must_use]
+    pub fn profile_name(&self) -> &str {
+        self.profile().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Every named profile, which is any directory in `profiles/`.
+    ///
+    /// # Errors
+    /// This function errors if `profiles/` exists but can't be read.
+    pub fn profiles(&self) -> Result<Vec<String>, io::Error> {
+        let entries = match read_dir(self.root.join("profiles")) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let mut profiles = vec![];
+
+        for entry in entries {
+            let entry = entry?;
+
+            if let Some(name) = entry.file_name().to_str() {
+                if entry.path().is_dir() && is_valid_profile_name(name) {
+                    profiles.push(name.to_string());
+                }
+            }
+        }
+
+        profiles.sort();
+
+        Ok(profiles)
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> PathBuf {
+        self.profile.as_ref().map_or_else(
+            || self.root.join("cache"),
+            |profile| self.root.join("cache").join(profile),
+        )
+    }
+
+    /// What belongs to the profile's account apart from its cache, the data root
+    /// itself for the default profile.
+    #[must_use]
+    pub fn profile_root(&self) -> PathBuf {
+        self.profile.as_ref().map_or_else(
+            || self.root.clone(),
+            |profile| self.root.join("profiles").join(profile),
+        )
+    }
+
+    #[must_use]
+    pub fn cache_year(&self, year: u64) -> PathBuf {
+        self.cache().join(year.to_string())
+    }
+
+    #[must_use]
+    pub fn input(&self, year: u64, day: u64) -> PathBuf {
+        self.cache_year(year).join(format!("day{day}.txt"))
+    }
+
+    #[must_use]
+    pub fn input_meta(&self, year: u64, day: u64) -> PathBuf {
+        self.cache_year(year).join(format!("day{day}.meta"))
+    }
+
+    #[must_use]
+    pub fn known_answers(&self, year: u64, day: u64) -> PathBuf {
+        self.cache_year(year).join(format!("day{day}.answers"))
+    }
+
+    #[must_use]
+    pub fn puzzle(&self, year: u64, day: u64) -> PathBuf {
+        self.cache_year(year).join(format!("day{day}.html"))
+    }
+
+    #[must_use]
+    pub fn calendar(&self, year: u64) -> PathBuf {
+        self.cache_year(year).join("calendar.html")
+    }
+
+    /// Stars and answers earned on the site, which differ per profile like the inputs do.
+    #[must_use]
+    pub fn earned(&self, year: u64) -> PathBuf {
+        self.cache_year(year).join("earned.txt")
+    }
+
+    #[must_use]
+    pub fn leaderboard(&self, year: u64, id: u64) -> PathBuf {
+        self.cache_year(year).join(format!("leaderboard{id}.json"))
+    }
+
+    #[must_use]
+    pub fn leaderboard_id_file(&self) -> PathBuf {
+        self.root.join("leaderboard.txt")
+    }
+
+    #[must_use]
+    pub fn ledger(&self, year: u64, day: u64) -> PathBuf {
+        self.profile_root()
+            .join(format!("ledger/{year}/day{day}.txt"))
+    }
+
+    #[must_use]
+    pub fn examples(&self, year: u64, day: u64) -> PathBuf {
+        self.root.join(format!("examples/{year}/day{day}"))
+    }
+
+    #[must_use]
+    pub fn custom(&self, year: u64, day: u64) -> PathBuf {
+        self.root.join(format!("custom/{year}/day{day}"))
+    }
+
+    #[must_use]
+    pub fn session_file(&self) -> PathBuf {
+        self.profile_root().join("session.txt")
+    }
+
+    #[must_use]
+    pub fn throttle_file(&self) -> PathBuf {
+        self.root.join("throttle.txt")
+    }
+
+    #[must_use]
+    pub fn request_log(&self) -> PathBuf {
+        self.root.join("requests.log")
+    }
+
+    /// Where responses that don't look like inputs are kept instead of the cache.
+    #[must_use]
+    pub fn quarantine(&self) -> PathBuf {
+        self.root.join("quarantine")
+    }
+
+    /// An empty data directory under the system's temp directory, so tests don't touch real data.
+    #[cfg(test)]
+    pub(crate) fn temporary(name: &str) -> Self {
+        let root = env::temp_dir().join(format!("rusty_xmas_{name}"));
+        let _unused_result = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+
+        Self::new(root)
+    }
+}
+
+#[test]
+fn should_prefer_variable_then_config_file() {
+    let root = env::temp_dir().join("rusty_xmas_data_root_lookup");
+    std::fs::create_dir_all(&root).unwrap();
+
+    let config = root.join(DATA_ROOT_CONFIG_FILE);
+    std::fs::write(&config, "/from/config\n").unwrap();
+
+    assert_eq!(
+        DataDir::resolve(Some(PathBuf::from("/from/env")), Some(config.clone())).root(),
+        Path::new("/from/env")
+    );
+    assert_eq!(
+        DataDir::resolve(Some(PathBuf::new()), Some(config)).root(),
+        Path::new("/from/config")
+    );
+    assert_eq!(
+        DataDir::resolve(None, Some(root.join("missing.txt"))).root(),
+        env::current_dir().unwrap().join(DEFAULT_DATA_ROOT)
+    );
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn should_namespace_profiles() {
+    let data = DataDir::temporary("profiles");
+
+    let alice = data.clone().with_profile(Some("alice"));
+    assert_eq!(
+        alice.input(2019, 1),
+        data.root().join("cache/alice/2019/day1.txt")
+    );
+    assert_eq!(
+        alice.session_file(),
+        data.root().join("profiles/alice/session.txt")
+    );
+    assert_eq!(data.input(2019, 1), data.root().join("cache/2019/day1.txt"));
+    assert_eq!(alice.throttle_file(), data.throttle_file());
+
+    for name in ["bob", "alice", "2019"] {
+        std::fs::create_dir_all(data.root().join("profiles").join(name)).unwrap();
+    }
+
+    assert_eq!(data.profiles().unwrap(), vec!["alice", "bob"]);
+    assert!(!is_valid_profile_name("../alice"));
+    assert!(!is_valid_profile_name(DEFAULT_PROFILE));
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
@@ -0,0 +1,186 @@
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, read_to_string, write},
+    io,
+};
+
+use super::{catalog::days_in_event, data_dir::DataDir, html};
+
+/// What has been earned on the site for one day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EarnedDay {
+    pub stars: u8,
+    pub answers: Vec<String>,
+}
+
+impl EarnedDay {
+    /// The last day only has one puzzle, its second star comes for free.
+    #[must_use]
+    pub fn expected_answers(&self, year: u64, day: u64) -> usize {
+        let puzzles = if day == days_in_event(year) { 1 } else { 2 };
+
+        usize::from(self.stars).min(puzzles)
+    }
+}
+
+/// Earned stars and answers for a year, stored in `earned.txt` next to the cached inputs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EarnedYear {
+    pub days: BTreeMap<u64, EarnedDay>,
+}
+
+impl EarnedYear {
+    #[must_use]
+    pub fn day(&self, day: u64) -> EarnedDay {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn stars(&self) -> u64 {
+        self.days.values().map(|day| u64::from(day.stars)).sum()
+    }
+
+    /// Lines look like `{day}\t{stars}\t{answers...}`, anything else is skipped.
+    #[must_use]
+    pub fn parse(data: &str) -> Self {
+        let days = data
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+
+                let day = fields.next()?.parse().ok()?;
+                let stars = fields.next()?.parse().ok()?;
+                let answers = fields.map(str::to_string).collect();
+
+                Some((day, EarnedDay { stars, answers }))
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    #[must_use]
+    pub fn serialize(&self) -> String {
+        self.days
+            .iter()
+            .map(|(day, earned)| {
+                let mut fields = vec![day.to_string(), earned.stars.to_string()];
+                fields.extend(earned.answers.iter().cloned());
+
+                fields.join("\t") + "\n"
+            })
+            .collect()
+    }
+
+    /// A year that was never synced has nothing earned.
+    #[must_use]
+    pub fn load(data: &DataDir, year: u64) -> Self {
+        read_to_string(data.earned(year))
+            .map(|earned| Self::parse(&earned))
+            .unwrap_or_default()
+    }
+
+    /// # Errors
+    /// This function errors if the file can't be written.
+    pub fn save(&self, data: &DataDir, year: u64) -> Result<(), io::Error> {
+        create_dir_all(data.cache_year(year))?;
+        write(data.earned(year), self.serialize())
+    }
+}
+
+/// Stars per day, read from the `aria-label`s of the year's calendar, like "Day 3, two stars".
+#[must_use]
+pub fn parse_calendar(page: &str) -> BTreeMap<u64, u8> {
+    let root = html::parse(page);
+
+    root.find_all(&|element| element.name == "a" && element.attribute("aria-label").is_some())
+        .into_iter()
+        .filter_map(|element| {
+            let label = element.attribute("aria-label")?.strip_prefix("Day ")?;
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+
+            let stars = match stars.trim() {
+                "two stars" => 2,
+                "one star" => 1,
+                _ => 0,
+            };
+
+            Some((day.trim().parse().ok()?, stars))
+        })
+        .collect()
+}
+
+/// The answers shown on a day's page under "Your puzzle answer was", in part order.
+#[must_use]
+pub fn parse_answers(page: &str) -> Vec<String> {
+    let root = html::parse(page);
+
+    root.find_all(&|element| {
+        element.name == "p"
+            && element
+                .text()
+                .trim_start()
+                .starts_with("Your puzzle answer was")
+    })
+    .into_iter()
+    .filter_map(|paragraph| {
+        paragraph
+            .find(&|element| element.name == "code")
+            .map(|code| code.text().trim().to_string())
+    })
+    .collect()
+}
+
+#[test]
+fn should_read_stars_from_calendar() {
+    let page = r#"<pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2019/day/1" class="calendar-day1 calendar-verycomplete">...</a>
+<a aria-label="Day 2, one star" href="/2019/day/2" class="calendar-day2 calendar-complete">...</a>
+<a aria-label="Day 3" href="/2019/day/3" class="calendar-day3">...</a>
+<span aria-hidden="true" class="calendar-day4">...</span>
+</pre>"#;
+
+    assert_eq!(
+        parse_calendar(page),
+        BTreeMap::from([(1, 2), (2, 1), (3, 0)])
+    );
+}
+
+#[test]
+fn should_read_submitted_answers() {
+    let page = r"<main>
+<article class='day-desc'><h2>--- Day 1: The Tyranny of the Rocket Equation ---</h2><p>...</p></article>
+<p>Your puzzle answer was <code>3267638</code>.</p>
+<article class='day-desc'><h2 id='part2'>--- Part Two ---</h2><p>...</p></article>
+<p>Your puzzle answer was <code>4898585</code>.</p>
+<p class='day-success'>Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main>";
+
+    assert_eq!(parse_answers(page), vec!["3267638", "4898585"]);
+}
+
+#[test]
+fn should_roundtrip_earned_progress() {
+    let mut earned = EarnedYear::default();
+    earned.days.insert(
+        1,
+        EarnedDay {
+            stars: 2,
+            answers: vec![String::from("3267638"), String::from("4898585")],
+        },
+    );
+    earned.days.insert(
+        25,
+        EarnedDay {
+            stars: 1,
+            answers: vec![],
+        },
+    );
+
+    assert_eq!(EarnedYear::parse(&earned.serialize()), earned);
+    assert_eq!(earned.stars(), 3);
+    assert_eq!(earned.day(25).expected_answers(2019, 25), 1);
+    assert_eq!(earned.day(25).expected_answers(2025, 12), 1);
+    assert_eq!(earned.day(25).expected_answers(2025, 25), 1);
+    assert_eq!(earned.day(2), EarnedDay::default());
+}
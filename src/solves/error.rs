@@ -0,0 +1,101 @@
+use std::{error::Error, fmt::Display, io, path::PathBuf};
+
+use super::{
+    data_dir::DataDir,
+    session::{config_path, SESSION_VAR},
+};
+
+#[derive(Debug)]
+pub enum DataError {
+    MissingSession,
+    ExpiredSession,
+    HttpStatus { status: u16, url: String },
+    Network(reqwest::Error),
+    NotUnlocked { year: u64, day: u64 },
+    CacheIo { path: PathBuf, source: io::Error },
+    InvalidContent(String),
+}
+
+impl DataError {
+    #[must_use]
+    pub fn cache_io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Self::CacheIo {
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// What the user can do about this error.
+    #[must_use]
+    pub fn next_step(&self) -> String {
+        match self {
+            Self::MissingSession => format!(
+                "Log in to adventofcode.com, copy the value of the `session` cookie and put it in ${SESSION_VAR}, {} or {}.",
+                config_path().map_or_else(
+                    || String::from("~/.config/rusty_xmas/session.txt"),
+                    |path| path.display().to_string()
+                ),
+                DataDir::current().session_file().display()
+            ),
+            Self::ExpiredSession => String::from(
+                "Log in to adventofcode.com again and replace the stored `session` cookie.",
+            ),
+            Self::HttpStatus { url, .. } => {
+                format!("Check {url} in a browser, and try again later if the site is having trouble.")
+            }
+            Self::Network(_) => String::from(
+                "Check your internet connection (and AOC_BASE_URL, if set) and try again.",
+            ),
+            Self::NotUnlocked { .. } => {
+                String::from("Wait until the puzzle unlocks at midnight US Eastern time.")
+            }
+            Self::CacheIo { path, .. } => format!(
+                "Check that {} is readable and writable, or delete it so it gets fetched again.",
+                path.display()
+            ),
+            Self::InvalidContent(_) => format!(
+                "Delete the cached file so it gets fetched again, or look at the quarantined responses in {}.",
+                DataDir::current().quarantine().display()
+            ),
+        }
+    }
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSession => write!(f, "Couldn't find a session key."),
+            Self::ExpiredSession => write!(
+                f,
+                "Advent of Code didn't accept the session key, it has probably expired."
+            ),
+            Self::HttpStatus { status, url } => {
+                write!(f, "Advent of Code answered {url} with status {status}.")
+            }
+            Self::Network(e) => write!(f, "Couldn't reach Advent of Code: {e}"),
+            Self::NotUnlocked { year, day } => {
+                write!(f, "Day {day} of {year} hasn't unlocked yet.")
+            }
+            Self::CacheIo { path, source } => {
+                write!(f, "Couldn't access {}: {source}", path.display())
+            }
+            Self::InvalidContent(reason) => write!(f, "Got invalid content: {reason}"),
+        }
+    }
+}
+
+impl Error for DataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Network(e) => Some(e),
+            Self::CacheIo { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for DataError {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Network(e)
+    }
+}
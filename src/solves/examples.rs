@@ -0,0 +1,215 @@
+use std::{
+    fmt::Write,
+    fs::{create_dir_all, read_to_string, write},
+    io,
+};
+
+use super::{
+    data_dir::DataDir,
+    html::{self, Element, Node},
+    params::parse_overrides,
+    year::Part,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: Option<String>,
+    /// Overrides of the day's parameters, for examples that use other constants.
+    pub params: Vec<(String, String)>,
+}
+
+/// Pulls one example per part out of a puzzle page.
+///
+/// The input is the first `<pre><code>` block of the part, falling back to part 1's
+/// when part 2 reuses it. The expected answer is the last emphasized code in the part,
+/// which is where Advent of Code puts the example's result.
+#[must_use]
+pub fn extract(html: &str) -> Vec<Example> {
+    let root = html::parse(html);
+    let articles =
+        root.find_all(&|element| element.name == "article" && element.has_class("day-desc"));
+
+    let mut examples: Vec<Example> = vec![];
+
+    for (article, part) in articles.into_iter().zip([Part::One, Part::Two]) {
+        let input = article
+            .find(&|element| element.name == "pre")
+            .map(Element::text)
+            .or_else(|| examples.last().map(|example| example.input.clone()));
+
+        let Some(input) = input else {
+            continue;
+        };
+
+        examples.push(Example {
+            part,
+            input,
+            answer: emphasized_answer(article),
+            params: vec![],
+        });
+    }
+
+    examples
+}
+
+fn emphasized_answer(article: &Element) -> Option<String> {
+    let is_only_child = |element: &Element, name: &str| {
+        let mut children = element.children.iter().filter(|child| match child {
+            Node::Text(text) => !text.trim().is_empty(),
+            Node::Element(_) => true,
+        });
+
+        matches!(
+            (children.next(), children.next()),
+            (Some(Node::Element(child)), None) if child.name == name
+        )
+    };
+
+    article
+        .find_all(&|element| {
+            (element.name == "code" && is_only_child(element, "em"))
+                || (element.name == "em" && is_only_child(element, "code"))
+        })
+        .last()
+        .map(|element| element.text().trim().to_string())
+}
+
+/// Saves examples as `part{n}_input.txt` and `part{n}_answer.txt`, so they can be fixed by hand.
+/// Parameters go in `part{n}_params.txt`, as `{name}={value}` lines.
+///
+/// # Errors
+/// This function errors if the fixtures can't be written.
+pub fn save(data: &DataDir, year: u64, day: u64, examples: &[Example]) -> Result<(), io::Error> {
+    let directory = data.examples(year, day);
+    create_dir_all(&directory)?;
+
+    for example in examples {
+        let part = example.part.number();
+
+        write(
+            directory.join(format!("part{part}_input.txt")),
+            &example.input,
+        )?;
+
+        if let Some(answer) = &example.answer {
+            write(directory.join(format!("part{part}_answer.txt")), answer)?;
+        }
+
+        if !example.params.is_empty() {
+            let params = example
+                .params
+                .iter()
+                .fold(String::new(), |mut params, (name, value)| {
+                    let _unused_result = writeln!(params, "{name}={value}");
+                    params
+                });
+
+            write(directory.join(format!("part{part}_params.txt")), params)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// # Errors
+/// This function errors if a fixture exists but can't be read.
+pub fn load(data: &DataDir, year: u64, day: u64) -> Result<Vec<Example>, io::Error> {
+    let directory = data.examples(year, day);
+    let mut examples = vec![];
+
+    for part in [Part::One, Part::Two] {
+        let number = part.number();
+
+        let input = match read_to_string(directory.join(format!("part{number}_input.txt"))) {
+            Ok(input) => input,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+
+        let answer = read_to_string(directory.join(format!("part{number}_answer.txt")))
+            .ok()
+            .map(|answer| answer.trim().to_string());
+
+        let params = read_to_string(directory.join(format!("part{number}_params.txt")))
+            .map(|params| parse_overrides(&params))
+            .unwrap_or_default();
+
+        examples.push(Example {
+            part,
+            input,
+            answer,
+            params,
+        });
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+const PAGE: &str = r"<main>
+<article class='day-desc'><h2>--- Day 6: Universal Orbit Map ---</h2>
+<p>For example, suppose you have the following map:</p>
+<pre><code>COM)B
+B)C
+C)D
+</code></pre>
+<p>In this example, <code>B</code> orbits <code>COM</code>.</p>
+<p>The total number of direct and indirect orbits in this example is <code><em>42</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>223251</code>.</p>
+<article class='day-desc'><h2 id='part2'>--- Part Two ---</h2>
+<p>The minimum number of transfers is <em><code>4</code></em>, see <em>above</em>.</p>
+</article>
+</main>";
+
+#[test]
+fn should_extract_examples_per_part() {
+    let examples = extract(PAGE);
+
+    assert_eq!(
+        examples,
+        vec![
+            Example {
+                part: Part::One,
+                input: String::from("COM)B\nB)C\nC)D\n"),
+                answer: Some(String::from("42")),
+                params: vec![],
+            },
+            Example {
+                part: Part::Two,
+                input: String::from("COM)B\nB)C\nC)D\n"),
+                answer: Some(String::from("4")),
+                params: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_leave_answer_empty_when_not_emphasized() {
+    let examples = extract(
+        "<article class=\"day-desc\"><pre><code>1</code></pre><p><em>No</em> answer</p></article>",
+    );
+
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].answer, None);
+}
+
+#[test]
+fn should_keep_params_next_to_the_example() {
+    let data = DataDir::temporary("example_params");
+
+    let example = Example {
+        part: Part::Two,
+        input: String::from("1,0,0,0,99"),
+        answer: Some(String::from("0")),
+        params: vec![(String::from("target"), String::from("2"))],
+    };
+
+    save(&data, 2019, 2, std::slice::from_ref(&example)).unwrap();
+    assert_eq!(load(&data, 2019, 2).unwrap(), vec![example]);
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
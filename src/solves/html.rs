@@ -0,0 +1,319 @@
+//! Just enough of an HTML parser to read the pages Advent of Code serves.
+//! It doesn't try to fix up broken markup beyond closing unclosed elements.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+const VOID_ELEMENTS: [&str; 8] = ["br", "hr", "img", "input", "link", "meta", "source", "wbr"];
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+impl Element {
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[must_use]
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attribute("class")
+            .is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+    }
+
+    /// All text inside this element, with markup stripped.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Element(element) => element.text(),
+                Node::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+
+    /// Every descendant matching `predicate`, in document order.
+    pub fn find_all(&self, predicate: &impl Fn(&Self) -> bool) -> Vec<&Self> {
+        let mut found = vec![];
+
+        for child in &self.children {
+            if let Node::Element(element) = child {
+                if predicate(element) {
+                    found.push(element);
+                }
+
+                found.extend(element.find_all(predicate));
+            }
+        }
+
+        found
+    }
+
+    pub fn find(&self, predicate: &impl Fn(&Self) -> bool) -> Option<&Self> {
+        self.find_all(predicate).into_iter().next()
+    }
+}
+
+/// Parses `html` into a tree under a nameless root element.
+#[must_use]
+pub fn parse(html: &str) -> Element {
+    let mut stack = vec![Element::default()];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let name = tag[..end].trim().to_lowercase();
+
+            if stack[1..].iter().any(|element| element.name == name) {
+                while let Some(element) = close(&mut stack) {
+                    if element == name {
+                        break;
+                    }
+                }
+            }
+
+            rest = tag.get(end + 1..).unwrap_or("");
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (element, self_closing, remaining) = parse_tag(&rest[1..]);
+            rest = remaining;
+
+            let name = element.name.clone();
+
+            if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                let closing = format!("</{name}");
+                let end = rest
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .unwrap_or(rest.len());
+
+                let mut element = element;
+                element.children.push(Node::Text(rest[..end].to_string()));
+                push(&mut stack, Node::Element(element));
+
+                rest = &rest[end..];
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            } else if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                push(&mut stack, Node::Element(element));
+            } else {
+                stack.push(element);
+            }
+        } else {
+            let skip = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[skip..].find('<').map_or(rest.len(), |end| end + skip);
+            push(&mut stack, Node::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    while close(&mut stack).is_some() {}
+
+    stack.pop().unwrap_or_default()
+}
+
+fn push(stack: &mut [Element], node: Node) {
+    stack
+        .last_mut()
+        .expect("The root element is never popped while parsing")
+        .children
+        .push(node);
+}
+
+/// Closes the innermost open element, returning its name.
+fn close(stack: &mut Vec<Element>) -> Option<String> {
+    if stack.len() < 2 {
+        return None;
+    }
+
+    let element = stack.pop()?;
+    let name = element.name.clone();
+    push(stack, Node::Element(element));
+
+    Some(name)
+}
+
+/// Parses everything after the `<` of an opening tag.
+fn parse_tag(tag: &str) -> (Element, bool, &str) {
+    let name_end = tag
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+
+    let mut element = Element {
+        name: tag[..name_end].to_lowercase(),
+        ..Element::default()
+    };
+
+    let mut rest = &tag[name_end..];
+
+    loop {
+        rest = rest.trim_start();
+
+        if let Some(remaining) = rest.strip_prefix("/>") {
+            return (element, true, remaining);
+        }
+
+        if let Some(remaining) = rest.strip_prefix('>') {
+            return (element, false, remaining);
+        }
+
+        if rest.is_empty() {
+            return (element, false, rest);
+        }
+
+        if let Some(remaining) = rest.strip_prefix('/') {
+            rest = remaining;
+            continue;
+        }
+
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = rest
+            .strip_prefix('=')
+            .map_or_else(String::new, |remaining| {
+                let remaining = remaining.trim_start();
+
+                if let Some(quote @ ('"' | '\'')) = remaining.chars().next() {
+                    let value_end = remaining[1..]
+                        .find(quote)
+                        .map_or(remaining.len(), |end| end + 1);
+                    rest = remaining.get(value_end + 1..).unwrap_or("");
+
+                    decode_entities(&remaining[1..value_end])
+                } else {
+                    let value_end = remaining
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(remaining.len());
+                    rest = &remaining[value_end..];
+
+                    decode_entities(&remaining[..value_end])
+                }
+            });
+
+        element.attributes.push((key, value));
+    }
+}
+
+#[must_use]
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..=end])?, end + 2)));
+
+        if let Some((character, length)) = entity {
+            decoded.push(character);
+            rest = &rest[length..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[test]
+fn should_parse_nested_elements() {
+    let root = parse(
+        "<!DOCTYPE html><main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>A <em>b</em><br/>c</p></article></main>",
+    );
+
+    let article = root.find(&|e| e.name == "article").unwrap();
+
+    assert!(article.has_class("day-desc"));
+    assert_eq!(article.text(), "--- Day 1 ---A bc");
+    assert_eq!(root.find_all(&|e| e.name == "br").len(), 1);
+}
+
+#[test]
+fn should_close_unclosed_elements() {
+    let root = parse("<div><p>one<p>two</div><span>three</span>");
+
+    assert_eq!(root.find_all(&|e| e.name == "p").len(), 2);
+    assert_eq!(root.find(&|e| e.name == "div").unwrap().text(), "onetwo");
+    assert_eq!(root.find(&|e| e.name == "span").unwrap().text(), "three");
+}
+
+#[test]
+fn should_skip_comments_and_scripts() {
+    let root = parse("<p>a<!-- <b>hidden</b> -->b</p><script>if (1 < 2) {}</script>");
+
+    assert_eq!(root.find(&|e| e.name == "p").unwrap().text(), "ab");
+    assert!(root.find(&|e| e.name == "b").is_none());
+    assert_eq!(
+        root.find(&|e| e.name == "script").unwrap().text(),
+        "if (1 < 2) {}"
+    );
+}
+
+#[test]
+fn should_keep_non_ascii_text() {
+    let root = parse("<p>\u{2192} <code>a < b</code> \u{2605}</p>");
+
+    assert_eq!(root.text(), "\u{2192} a < b \u{2605}");
+}
+
+#[test]
+fn should_decode_entities() {
+    assert_eq!(
+        decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &unknown; & d"),
+        "a <b> & 'c' &unknown; & d"
+    );
+
+    let root = parse("<a href='/2019/day/1' title=\"&quot;x&quot;\">link</a>");
+    let link = root.find(&|e| e.name == "a").unwrap();
+
+    assert_eq!(link.attribute("href"), Some("/2019/day/1"));
+    assert_eq!(link.attribute("title"), Some("\"x\""));
+}
@@ -0,0 +1,212 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    io,
+};
+
+use super::{
+    data_dir::DataDir,
+    solution::{Answer, SolveError},
+    year::{AdventOfCodeDay, Part},
+};
+
+/// Accepted answers for a day, stored in `day{day}.answers` next to the cached input
+/// since they only hold for that input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    answers: [Option<String>; 2],
+}
+
+impl KnownAnswers {
+    #[must_use]
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.answers[usize::from(part.number() - 1)].as_deref()
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        self.answers[usize::from(part.number() - 1)] = Some(answer.trim().to_string());
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.answers.iter().all(Option::is_none)
+    }
+
+    /// Lines look like `part{number}\t{answer}`, anything else is skipped.
+    #[must_use]
+    pub fn parse(data: &str) -> Self {
+        let mut known = Self::default();
+
+        for line in data.lines() {
+            match line.split_once('\t') {
+                Some(("part1", answer)) => known.set(Part::One, answer),
+                Some(("part2", answer)) => known.set(Part::Two, answer),
+                _ => (),
+            }
+        }
+
+        known
+    }
+
+    #[must_use]
+    pub fn serialize(&self) -> String {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| Some(format!("part{}\t{}\n", part.number(), self.get(part)?)))
+            .collect()
+    }
+
+    /// A day without known answers has an empty registry.
+    #[must_use]
+    pub fn load(data: &DataDir, year: u64, day: u64) -> Self {
+        read_to_string(data.known_answers(year, day))
+            .map(|known| Self::parse(&known))
+            .unwrap_or_default()
+    }
+
+    /// # Errors
+    /// This function errors if the file can't be written.
+    pub fn save(&self, data: &DataDir, year: u64, day: u64) -> Result<(), io::Error> {
+        create_dir_all(data.cache_year(year))?;
+        write(data.known_answers(year, day), self.serialize())
+    }
+}
+
+/// How a rerun compares to the known answer for a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Match(Answer),
+    Mismatch {
+        expected: String,
+        got: Answer,
+    },
+    Failed(SolveError),
+    /// Nothing to compare against, so the part wasn't run.
+    Unknown,
+}
+
+impl Check {
+    #[must_use]
+    pub const fn is_regression(&self) -> bool {
+        matches!(self, Self::Mismatch { .. } | Self::Failed(_))
+    }
+}
+
+/// Reruns the solved parts of `day` that have a known answer, parsing `input` only once.
+#[must_use]
+pub fn verify(day: &AdventOfCodeDay, input: &str, known: &KnownAnswers) -> Vec<(Part, Check)> {
+    let parts = day.parts();
+
+    if parts.iter().all(|part| known.get(*part).is_none()) {
+        return parts
+            .into_iter()
+            .map(|part| (part, Check::Unknown))
+            .collect();
+    }
+
+    let parsed = day.parse(input);
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let Some(expected) = known.get(part) else {
+                return (part, Check::Unknown);
+            };
+
+            let result = match &parsed {
+                Some(Ok(parsed)) => day.solve(parsed, part),
+                Some(Err(e)) => Some(Err(e.clone())),
+                None => None,
+            };
+
+            let check = match result {
+                Some(Ok(got)) if got == expected => Check::Match(got),
+                Some(Ok(got)) => Check::Mismatch {
+                    expected: expected.to_string(),
+                    got,
+                },
+                Some(Err(e)) => Check::Failed(e),
+                None => Check::Unknown,
+            };
+
+            (part, check)
+        })
+        .collect()
+}
+
+#[test]
+fn should_roundtrip_known_answers() {
+    let data = DataDir::temporary("known_answers");
+
+    assert!(KnownAnswers::load(&data, 2019, 1).is_empty());
+
+    let mut known = KnownAnswers::default();
+    known.set(Part::Two, "4898585\n");
+    known.save(&data, 2019, 1).unwrap();
+
+    let loaded = KnownAnswers::load(&data, 2019, 1);
+    assert_eq!(loaded.get(Part::One), None);
+    assert_eq!(loaded.get(Part::Two), Some("4898585"));
+    assert_eq!(loaded, known);
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
+
+#[test]
+fn should_flag_regressions() {
+    use super::solution::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        const YEAR: u64 = 2019;
+        const DAY: u64 = 1;
+
+        fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().map_err(|_| SolveError::invalid(n)))
+                .collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.iter().sum::<i64>().into())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.iter().product::<i64>().into())
+        }
+    }
+
+    let day = AdventOfCodeDay::new::<Sum>("Sum");
+
+    let mut known = KnownAnswers::default();
+    assert_eq!(
+        verify(&day, "2 3", &known),
+        vec![(Part::One, Check::Unknown), (Part::Two, Check::Unknown)]
+    );
+
+    known.set(Part::One, "5");
+    known.set(Part::Two, "7");
+
+    let checks = verify(&day, "2 3", &known);
+    assert_eq!(checks[0], (Part::One, Check::Match(Answer::Number(5))));
+    assert_eq!(
+        checks[1],
+        (
+            Part::Two,
+            Check::Mismatch {
+                expected: String::from("7"),
+                got: Answer::Number(6)
+            }
+        )
+    );
+    assert!(checks[1].1.is_regression());
+
+    let checks = verify(&day, "2 x", &known);
+    assert_eq!(
+        checks[0].1,
+        Check::Failed(SolveError::Invalid(String::from("x")))
+    );
+}
@@ -0,0 +1,157 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::Deserialize;
+
+use super::{error::DataError, unlock::unlock_time, year::Part};
+
+pub const LEADERBOARD_VAR: &str = "AOC_LEADERBOARD_ID";
+
+/// Advent of Code asks for private leaderboards to be requested at most once every 15 minutes.
+pub const LEADERBOARD_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u64,
+    pub local_score: u64,
+    pub last_star_ts: u64,
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+impl Member {
+    /// Members without a public name show up like they do on the site.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// Unix time at which the star for `part` of `day` was earned.
+    #[must_use]
+    pub fn star_time(&self, day: u64, part: Part) -> Option<u64> {
+        let star = self
+            .completion_day_level
+            .get(&day.to_string())?
+            .get(&part.number().to_string())?;
+
+        Some(star.get_star_ts)
+    }
+
+    /// How long after the puzzle unlocked the star was earned.
+    #[must_use]
+    pub fn star_delay(&self, year: u64, day: u64, part: Part) -> Option<Duration> {
+        let unlock = unlock_time(year, day)
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?;
+
+        Some(Duration::from_secs(self.star_time(day, part)?).saturating_sub(unlock))
+    }
+
+    /// Time between the first and second star of a day.
+    #[must_use]
+    pub fn delta(&self, day: u64) -> Option<Duration> {
+        let first = self.star_time(day, Part::One)?;
+        let second = self.star_time(day, Part::Two)?;
+
+        Some(Duration::from_secs(second.saturating_sub(first)))
+    }
+
+    /// Days with at least one star, in order.
+    #[must_use]
+    pub fn days(&self) -> Vec<u64> {
+        let mut days: Vec<u64> = self
+            .completion_day_level
+            .keys()
+            .filter_map(|day| day.parse().ok())
+            .collect();
+
+        days.sort_unstable();
+        days
+    }
+}
+
+/// The JSON served at `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    /// # Errors
+    /// This function errors if `json` isn't a private leaderboard.
+    pub fn parse(json: &str) -> Result<Self, DataError> {
+        serde_json::from_str(json).map_err(|e| {
+            DataError::InvalidContent(format!("the leaderboard isn't what we expected: {e}"))
+        })
+    }
+
+    /// Members by local score, ties going to whoever got their last star first.
+    #[must_use]
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+
+        members
+    }
+}
+
+#[cfg(test)]
+const FIXTURE: &str = include_str!("fixtures/leaderboard_2019.json");
+
+#[test]
+fn should_parse_recorded_leaderboard() {
+    let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+    assert_eq!(leaderboard.owner_id, 101);
+    assert_eq!(leaderboard.event, "2019");
+    assert_eq!(leaderboard.members.len(), 3);
+
+    assert_eq!(
+        leaderboard
+            .standings()
+            .iter()
+            .map(|member| member.display_name())
+            .collect::<Vec<_>>(),
+        vec!["(anonymous user #202)", "pehdfms", "lurker"]
+    );
+}
+
+#[test]
+fn should_time_stars_and_deltas() {
+    let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+    let member = &leaderboard.members["101"];
+
+    assert_eq!(member.days(), vec![1, 2]);
+    assert_eq!(member.star_time(1, Part::One), Some(1_575_177_023));
+    assert_eq!(
+        member.star_delay(2019, 1, Part::One),
+        Some(Duration::from_secs(623))
+    );
+    assert_eq!(member.delta(1), Some(Duration::from_secs(588)));
+    assert_eq!(leaderboard.members["202"].delta(2), None);
+    assert_eq!(leaderboard.members["303"].days(), Vec::<u64>::new());
+}
+
+#[test]
+fn should_reject_other_json() {
+    assert!(matches!(
+        Leaderboard::parse("{\"error\": \"not found\"}"),
+        Err(DataError::InvalidContent(_))
+    ));
+}
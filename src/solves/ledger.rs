@@ -0,0 +1,351 @@
+use std::{
+    error::Error,
+    fmt::{Display, Write as _},
+    fs::{create_dir_all, File},
+    io::{self, Read, Write},
+};
+
+use super::{data_dir::DataDir, submit::Verdict, year::Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Outcome {
+    /// Only verdicts that tell us something about the answer are worth keeping.
+    #[must_use]
+    pub const fn from_verdict(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Correct => Some(Self::Correct),
+            Verdict::TooHigh => Some(Self::TooHigh),
+            Verdict::TooLow => Some(Self::TooLow),
+            Verdict::Wrong => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+
+    const fn tag(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wrong => "wrong",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "correct" => Some(Self::Correct),
+            "too_high" => Some(Self::TooHigh),
+            "too_low" => Some(Self::TooLow),
+            "wrong" => Some(Self::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved { answer: String },
+    AlreadyWrong { answer: String, outcome: Outcome },
+    NotBelow { answer: String, bound: i128 },
+    NotAbove { answer: String, bound: i128 },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadySolved { answer } => {
+                write!(f, "Refusing to submit, {answer} was already accepted.")
+            }
+            Self::AlreadyWrong { answer, outcome } => write!(
+                f,
+                "Refusing to submit {answer}, it was already marked as {outcome}."
+            ),
+            Self::NotBelow { answer, bound } => write!(
+                f,
+                "Refusing to submit {answer}, {bound} was already too high."
+            ),
+            Self::NotAbove { answer, bound } => write!(
+                f,
+                "Refusing to submit {answer}, {bound} was already too low."
+            ),
+        }
+    }
+}
+
+impl Error for Rejection {}
+
+/// Every informative verdict received for one day, stored in `ledger/{year}/day{day}.txt`
+/// in the profile's directory, since verdicts only hold for that account's input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// # Errors
+    /// This function errors if the ledger exists but can't be read.
+    pub fn load(data: &DataDir, year: u64, day: u64) -> Result<Self, io::Error> {
+        let mut file = match File::open(data.ledger(year, day)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut data = String::new();
+        file.read_to_string(&mut data)?;
+
+        Ok(Self::parse(&data))
+    }
+
+    /// # Errors
+    /// This function errors if the ledger can't be written.
+    pub fn save(&self, data: &DataDir, year: u64, day: u64) -> Result<(), io::Error> {
+        let path = data.ledger(year, day);
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        let mut file = File::create(path)?;
+        file.write_all(self.serialize().as_bytes())
+    }
+
+    /// Lines look like `{part}\t{outcome}\t{answer}`, anything else is skipped.
+    #[must_use]
+    pub fn parse(data: &str) -> Self {
+        let entries = data
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+
+                let part = match fields.next()? {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return None,
+                };
+                let outcome = Outcome::from_tag(fields.next()?)?;
+                let answer = fields.next()?.to_string();
+
+                Some(Entry {
+                    part,
+                    answer,
+                    outcome,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    #[must_use]
+    pub fn serialize(&self) -> String {
+        self.entries
+            .iter()
+            .fold(String::new(), |mut serialized, entry| {
+                let _unused_result = writeln!(
+                    serialized,
+                    "{}\t{}\t{}",
+                    entry.part.number(),
+                    entry.outcome.tag(),
+                    entry.answer
+                );
+                serialized
+            })
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, verdict: &Verdict) {
+        if let Some(outcome) = Outcome::from_verdict(verdict) {
+            self.entries.push(Entry {
+                part,
+                answer: answer.to_string(),
+                outcome,
+            });
+        }
+    }
+
+    /// Returns the (lower, upper) bounds an answer has to be strictly between.
+    #[must_use]
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let numbers = |outcome| {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.part == part && entry.outcome == outcome)
+                .filter_map(|entry| entry.answer.parse::<i128>().ok())
+        };
+
+        (
+            numbers(Outcome::TooLow).max(),
+            numbers(Outcome::TooHigh).min(),
+        )
+    }
+
+    /// # Errors
+    /// This function errors if the ledger already tells us the answer can't be right.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Rejection> {
+        let answer = answer.trim();
+
+        for entry in self.entries.iter().filter(|entry| entry.part == part) {
+            if entry.outcome == Outcome::Correct {
+                return Err(Rejection::AlreadySolved {
+                    answer: entry.answer.clone(),
+                });
+            }
+
+            if entry.answer == answer {
+                return Err(Rejection::AlreadyWrong {
+                    answer: answer.to_string(),
+                    outcome: entry.outcome,
+                });
+            }
+        }
+
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        match self.bounds(part) {
+            (_, Some(bound)) if number >= bound => Err(Rejection::NotBelow {
+                answer: answer.to_string(),
+                bound,
+            }),
+            (Some(bound), _) if number <= bound => Err(Rejection::NotAbove {
+                answer: answer.to_string(),
+                bound,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Ledger, Outcome, Rejection};
+    use crate::solves::{data_dir::DataDir, submit::Verdict, year::Part};
+
+    fn ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+
+        ledger.record(Part::One, "500", &Verdict::TooHigh);
+        ledger.record(Part::One, "100", &Verdict::TooLow);
+        ledger.record(Part::One, "300", &Verdict::Wrong);
+        ledger.record(
+            Part::One,
+            "250",
+            &Verdict::RateLimited {
+                wait: std::time::Duration::from_secs(5),
+            },
+        );
+
+        ledger
+    }
+
+    #[test]
+    fn should_only_record_informative_verdicts() {
+        assert_eq!(ledger().entries().len(), 3);
+    }
+
+    #[test]
+    fn should_roundtrip_through_text() {
+        let ledger = ledger();
+
+        assert_eq!(Ledger::parse(&ledger.serialize()), ledger);
+        assert_eq!(ledger.entries()[0].outcome, Outcome::TooHigh);
+    }
+
+    #[test]
+    fn should_refuse_known_bad_answers() {
+        let ledger = ledger();
+
+        assert!(matches!(
+            ledger.check(Part::One, "300"),
+            Err(Rejection::AlreadyWrong { .. })
+        ));
+
+        assert_eq!(
+            ledger.check(Part::One, "600"),
+            Err(Rejection::NotBelow {
+                answer: String::from("600"),
+                bound: 500
+            })
+        );
+
+        assert_eq!(
+            ledger.check(Part::One, "100"),
+            Err(Rejection::AlreadyWrong {
+                answer: String::from("100"),
+                outcome: Outcome::TooLow
+            })
+        );
+
+        assert!(matches!(
+            ledger.check(Part::One, "42"),
+            Err(Rejection::NotAbove { bound: 100, .. })
+        ));
+    }
+
+    #[test]
+    fn should_allow_answers_inside_bounds() {
+        let ledger = ledger();
+
+        assert_eq!(ledger.check(Part::One, "250"), Ok(()));
+        assert_eq!(ledger.check(Part::One, "abc"), Ok(()));
+        assert_eq!(ledger.check(Part::Two, "600"), Ok(()));
+    }
+
+    #[test]
+    fn should_keep_a_ledger_per_profile() {
+        let data = DataDir::temporary("ledger_profiles");
+        let alice = data.clone().with_profile(Some("alice"));
+
+        ledger().save(&data, 2019, 1).unwrap();
+
+        assert_eq!(Ledger::load(&data, 2019, 1).unwrap(), ledger());
+        assert_eq!(Ledger::load(&alice, 2019, 1).unwrap(), Ledger::default());
+        assert_eq!(
+            alice.ledger(2019, 1),
+            data.root().join("profiles/alice/ledger/2019/day1.txt")
+        );
+
+        std::fs::remove_dir_all(data.root()).unwrap();
+    }
+
+    #[test]
+    fn should_refuse_after_correct_answer() {
+        let mut ledger = ledger();
+        ledger.record(Part::One, "250", &Verdict::Correct);
+
+        assert!(matches!(
+            ledger.check(Part::One, "251"),
+            Err(Rejection::AlreadySolved { .. })
+        ));
+    }
+}
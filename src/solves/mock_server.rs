@@ -0,0 +1,211 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Header names are stored lowercased, so `name` should be lowercase too.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, MockResponse>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// In-process stand-in for the Advent of Code website.
+///
+/// Responses are registered per path and served for any method. Every request
+/// is recorded so tests can assert on what was sent.
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// # Errors
+    /// This function errors if it can't bind to a local port.
+    pub fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(State::default()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let handle = {
+            let state = Arc::clone(&state);
+            let running = Arc::clone(&running);
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if !running.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    if let Ok(stream) = stream {
+                        let _unused_result = handle_connection(stream, &state);
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            address,
+            state,
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// # Panics
+    /// If a previous request handler panicked while holding the state lock.
+    pub fn respond(&self, path: &str, status: u16, body: &str) {
+        self.state
+            .lock()
+            .expect("Mock server state poisoned")
+            .routes
+            .insert(
+                path.to_string(),
+                MockResponse {
+                    status,
+                    body: body.to_string(),
+                },
+            );
+    }
+
+    pub fn serve_input(&self, year: u64, day: u64, input: &str) {
+        self.respond(&format!("/{year}/day/{day}/input"), 200, input);
+    }
+
+    /// # Panics
+    /// If a previous request handler panicked while holding the state lock.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .lock()
+            .expect("Mock server state poisoned")
+            .requests
+            .clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+
+        // The accept loop only notices the flag once another connection comes in.
+        let _unused_result = TcpStream::connect(self.address);
+
+        if let Some(handle) = self.handle.take() {
+            let _unused_result = handle.join();
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let content_length = headers
+        .iter()
+        .find(|(key, _)| key == "content-length")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let response = {
+        let mut state = state.lock().expect("Mock server state poisoned");
+        let response = state.routes.get(&path).cloned();
+
+        state.requests.push(RecordedRequest {
+            method,
+            path,
+            headers,
+            body: String::from_utf8_lossy(&body).to_string(),
+        });
+        drop(state);
+
+        response.unwrap_or_else(|| MockResponse {
+            status: 404,
+            body: String::from("404 Not Found"),
+        })
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+
+    stream.flush()
+}
+
+#[test]
+fn should_answer_unknown_paths_with_404() {
+    let server = MockServer::start().unwrap();
+
+    let response = reqwest::blocking::get(format!("{}/2019/day/1/input", server.url())).unwrap();
+
+    assert_eq!(response.status().as_u16(), 404);
+    assert_eq!(server.requests()[0].path, "/2019/day/1/input");
+}
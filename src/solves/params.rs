@@ -0,0 +1,225 @@
+use std::{borrow::Cow, error::Error, fmt::Display};
+
+/// The value of a [`Param`]. Overrides have to keep the type of the default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamValue {
+    Number(i64),
+    Text(Cow<'static, str>),
+}
+
+impl ParamValue {
+    /// Parses `raw` as the same type as `self`.
+    fn parse_like(&self, raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+
+        match self {
+            Self::Number(_) => raw.replace('_', "").parse().ok().map(Self::Number),
+            Self::Text(_) => Some(Self::Text(Cow::Owned(raw.to_string()))),
+        }
+    }
+
+    const fn kind(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "a number",
+            Self::Text(_) => "text",
+        }
+    }
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// A constant the puzzle could have picked differently, like the target of a search.
+/// Examples often use another one than the real input.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: ParamValue,
+}
+
+impl Param {
+    #[must_use]
+    pub const fn number(name: &'static str, description: &'static str, default: i64) -> Self {
+        Self {
+            name,
+            description,
+            default: ParamValue::Number(default),
+        }
+    }
+
+    #[must_use]
+    pub const fn text(
+        name: &'static str,
+        description: &'static str,
+        default: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            default: ParamValue::Text(Cow::Borrowed(default)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown(String),
+    Invalid {
+        name: String,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "There's no parameter called {name}"),
+            Self::Invalid {
+                name,
+                value,
+                expected,
+            } => write!(f, "{name} should be {expected}, got {value}"),
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// The values of a day's parameters, starting from their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, ParamValue)>,
+}
+
+impl Params {
+    #[must_use]
+    pub fn defaults(params: &[Param]) -> Self {
+        Self {
+            values: params
+                .iter()
+                .map(|param| (param.name, param.default.clone()))
+                .collect(),
+        }
+    }
+
+    /// Overrides `name` with `raw`, parsed as the type of its default.
+    ///
+    /// # Errors
+    /// This function errors if there's no such parameter or `raw` doesn't parse.
+    pub fn set(&mut self, name: &str, raw: &str) -> Result<(), ParamError> {
+        let (name, value) = self
+            .values
+            .iter_mut()
+            .find(|(param, _)| *param == name.trim())
+            .ok_or_else(|| ParamError::Unknown(name.trim().to_string()))?;
+
+        *value = value.parse_like(raw).ok_or_else(|| ParamError::Invalid {
+            name: (*name).to_string(),
+            value: raw.trim().to_string(),
+            expected: value.kind(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Applies every override, stopping at the first one that doesn't fit.
+    ///
+    /// # Errors
+    /// This function errors if an override doesn't fit, see [`Params::set`].
+    pub fn apply(&mut self, overrides: &[(String, String)]) -> Result<(), ParamError> {
+        overrides
+            .iter()
+            .try_for_each(|(name, raw)| self.set(name, raw))
+    }
+
+    /// # Panics
+    /// If the day didn't declare `name` as a number.
+    #[must_use]
+    pub fn number(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Some(ParamValue::Number(n)) => *n,
+            _ => panic!("{name} should be declared as a number parameter"),
+        }
+    }
+
+    /// # Panics
+    /// If the day didn't declare `name` as text.
+    #[must_use]
+    pub fn text(&self, name: &str) -> &str {
+        match self.get(name) {
+            Some(ParamValue::Text(text)) => text,
+            _ => panic!("{name} should be declared as a text parameter"),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ParamValue> {
+        self.values
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ParamValue)> {
+        self.values.iter().map(|(name, value)| (*name, value))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Lines look like `{name}={value}`, blank lines and lines starting with `#` are skipped.
+#[must_use]
+pub fn parse_overrides(data: &str) -> Vec<(String, String)> {
+    data.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+const PARAMS: &[Param] = &[
+    Param::number("target", "What to search for", 19_690_720),
+    Param::text("start", "Where to start", "YOU"),
+];
+
+#[test]
+fn should_override_defaults_with_the_same_type() {
+    let mut params = Params::defaults(PARAMS);
+
+    assert_eq!(params.number("target"), 19_690_720);
+    assert_eq!(params.text("start"), "YOU");
+
+    params
+        .apply(&parse_overrides("# example\ntarget = 1_000\n\nstart=COM\n"))
+        .unwrap();
+
+    assert_eq!(params.number("target"), 1000);
+    assert_eq!(params.text("start"), "COM");
+
+    assert_eq!(
+        params.set("target", "many"),
+        Err(ParamError::Invalid {
+            name: String::from("target"),
+            value: String::from("many"),
+            expected: "a number",
+        })
+    );
+    assert_eq!(
+        params.set("missing", "1"),
+        Err(ParamError::Unknown(String::from("missing")))
+    );
+    assert_eq!(params.number("target"), 1000);
+}
@@ -0,0 +1,52 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use super::error::DataError;
+
+pub trait InputProvider {
+    /// # Errors
+    /// This function errors if the input for the given day can't be provided.
+    fn input(&self, year: u64, day: u64) -> Result<String, DataError>;
+
+    /// Identifies the session inputs come from, when there is one.
+    fn session_fingerprint(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Serves inputs from `{root}/{year}/day{day}.txt`, the same layout used by the cache.
+pub struct FileProvider {
+    root: PathBuf,
+}
+
+impl FileProvider {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl InputProvider for FileProvider {
+    fn input(&self, year: u64, day: u64) -> Result<String, DataError> {
+        let path = self.root.join(format!("{year}/day{day}.txt"));
+
+        read_to_string(&path).map_err(|e| DataError::cache_io(path, e))
+    }
+}
+
+#[test]
+fn should_read_inputs_from_root() {
+    let root = std::env::temp_dir().join("rusty_xmas_file_provider");
+    std::fs::create_dir_all(root.join("2019")).unwrap();
+    std::fs::write(root.join("2019/day1.txt"), "12\n14").unwrap();
+
+    let provider = FileProvider::new(&root);
+
+    assert_eq!(provider.input(2019, 1).unwrap(), "12\n14");
+    assert!(provider.input(2019, 2).is_err());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,211 @@
+use std::fmt::Write;
+
+use colored::{Color, Colorize};
+
+use super::html::{self, Element, Node};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Style {
+    bold: bool,
+    underline: bool,
+    color: Option<Color>,
+}
+
+impl Style {
+    fn nested(self, element: &Element) -> Self {
+        match element.name.as_str() {
+            "em" if element.has_class("star") => Self {
+                color: Some(Color::BrightYellow),
+                ..self
+            },
+            "em" => Self {
+                bold: true,
+                color: self.color.or(Some(Color::BrightWhite)),
+                ..self
+            },
+            "code" => Self {
+                color: Some(Color::BrightGreen),
+                ..self
+            },
+            "a" => Self {
+                underline: true,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    fn apply(self, text: &str) -> String {
+        let mut styled = text.normal();
+
+        if let Some(color) = self.color {
+            styled = styled.color(color);
+        }
+
+        if self.bold {
+            styled = styled.bold();
+        }
+
+        if self.underline {
+            styled = styled.underline();
+        }
+
+        styled.to_string()
+    }
+}
+
+/// Renders the puzzle descriptions in an Advent of Code day page as terminal text.
+///
+/// Both parts live in their own `<article class="day-desc">`, so part 2 shows up
+/// once the page has been fetched after solving part 1.
+#[must_use]
+pub fn render(html: &str) -> String {
+    html::parse(html)
+        .find_all(&|element| element.name == "article" && element.has_class("day-desc"))
+        .into_iter()
+        .map(render_blocks)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Title of the puzzle, e.g. "The Tyranny of the Rocket Equation".
+#[must_use]
+pub fn title(html: &str) -> Option<String> {
+    let root = html::parse(html);
+    let heading = root.find(&|element| element.name == "h2")?.text();
+
+    heading
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .split_once(": ")
+        .map(|(_, title)| title.to_string())
+}
+
+fn render_blocks(article: &Element) -> String {
+    let mut rendered = String::new();
+
+    for child in &article.children {
+        let Node::Element(element) = child else {
+            continue;
+        };
+
+        match element.name.as_str() {
+            "h2" => {
+                let _unused_result =
+                    writeln!(rendered, "{}\n", element.text().bright_white().bold());
+            }
+            "pre" => {
+                for line in element.text().trim_end_matches('\n').lines() {
+                    let _unused_result = writeln!(rendered, "    {}", line.bright_green());
+                }
+
+                rendered.push('\n');
+            }
+            "ul" | "ol" => {
+                for item in element.find_all(&|element| element.name == "li") {
+                    let _unused_result = writeln!(
+                        rendered,
+                        "  - {}",
+                        render_inline(item, Style::default()).trim()
+                    );
+                }
+
+                rendered.push('\n');
+            }
+            _ => {
+                let _unused_result = writeln!(
+                    rendered,
+                    "{}\n",
+                    render_inline(element, Style::default()).trim()
+                );
+            }
+        }
+    }
+
+    rendered
+}
+
+fn render_inline(element: &Element, style: Style) -> String {
+    let style = style.nested(element);
+
+    element
+        .children
+        .iter()
+        .map(|child| match child {
+            Node::Element(element) => render_inline(element, style),
+            Node::Text(text) => style.apply(&collapse_whitespace(text)),
+        })
+        .collect()
+}
+
+/// Collapses whitespace like a browser would, keeping a single space at each edge.
+fn collapse_whitespace(text: &str) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    if words.is_empty() {
+        return if text.is_empty() {
+            String::new()
+        } else {
+            String::from(" ")
+        };
+    }
+
+    let leading = if text.starts_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+
+    let trailing = if text.ends_with(char::is_whitespace) {
+        " "
+    } else {
+        ""
+    };
+
+    format!("{leading}{}{trailing}", words.join(" "))
+}
+
+#[cfg(test)]
+const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: The Tyranny of the Rocket Equation ---</h2>
+<p>Fuel required to launch a given <em>module</em> is based on its <em>mass</em>.</p>
+<ul>
+<li>For a mass of <code>12</code>, get <code>2</code>.</li>
+</ul>
+<pre><code>12
+14
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>3232358</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>What is the sum of the fuel requirements?</p>
+</article>
+</main></body></html>"#;
+
+#[test]
+fn should_render_both_parts() {
+    let rendered = render(PAGE);
+
+    assert!(rendered.contains("--- Day 1: The Tyranny of the Rocket Equation ---"));
+    assert!(rendered.contains("--- Part Two ---"));
+    assert!(rendered.contains("What is the sum of the fuel requirements?"));
+    assert!(rendered.contains("    "));
+    assert!(rendered.contains("  - For a mass of "));
+    assert!(!rendered.contains("Your puzzle answer was"));
+}
+
+#[test]
+fn should_read_title() {
+    assert_eq!(
+        title(PAGE).as_deref(),
+        Some("The Tyranny of the Rocket Equation")
+    );
+    assert_eq!(title("<p>No heading</p>"), None);
+}
+
+#[test]
+fn should_collapse_whitespace() {
+    assert_eq!(collapse_whitespace("\n  a   b\n"), " a b ");
+    assert_eq!(collapse_whitespace("a"), "a");
+    assert_eq!(collapse_whitespace("\n"), " ");
+}
@@ -0,0 +1,512 @@
+use std::{
+    error::Error,
+    fmt::{Display, Write},
+    fs::{create_dir_all, read_to_string, write},
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::{catalog::is_event_day, examples::Example, year::Part};
+
+/// Where rustfmt would start breaking a list over several lines.
+const MAX_WIDTH: usize = 100;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// Days can only be generated from inside a checkout of this repository.
+    NoSources(PathBuf),
+    NotOnCalendar {
+        year: u64,
+        day: u64,
+    },
+    AlreadyExists(PathBuf),
+    /// A file that should be edited doesn't look like it was generated.
+    Unrecognized {
+        path: PathBuf,
+        expected: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ScaffoldError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSources(directory) => write!(
+                f,
+                "Couldn't find the sources of rusty_xmas in {} or above it, run this from a checkout of the repository.",
+                directory.display()
+            ),
+            Self::NotOnCalendar { year, day } => {
+                write!(
+                    f,
+                    "Day {day} of {year} isn't on the Advent of Code calendar."
+                )
+            }
+            Self::AlreadyExists(path) => write!(f, "{} already exists.", path.display()),
+            Self::Unrecognized { path, expected } => {
+                write!(f, "Couldn't find {expected} in {}.", path.display())
+            }
+            Self::Io { path, source } => write!(f, "Couldn't access {}: {source}", path.display()),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The `src` directory of the checkout `start` is in, found by walking up to the
+/// directory with `Cargo.toml` and `src/solves.rs`.
+///
+/// # Errors
+/// This function errors if `start` isn't inside a checkout of this repository.
+pub fn find_source_root(start: &Path) -> Result<PathBuf, ScaffoldError> {
+    start
+        .ancestors()
+        .find(|directory| {
+            directory.join("Cargo.toml").is_file() && directory.join("src/solves.rs").is_file()
+        })
+        .map(|directory| directory.join("src"))
+        .ok_or_else(|| ScaffoldError::NoSources(start.to_path_buf()))
+}
+
+#[must_use]
+pub fn day_file(src: &Path, year: u64, day: u64) -> PathBuf {
+    src.join(format!("solves/y{year}/days/day{day}.rs"))
+}
+
+/// Whether [`scaffold_day`] would be able to generate the day, checked before anything is fetched for it.
+///
+/// # Errors
+/// This function errors if the day isn't on the calendar or already exists.
+pub fn check_new_day(src: &Path, year: u64, day: u64) -> Result<(), ScaffoldError> {
+    if !is_event_day(year, day) {
+        return Err(ScaffoldError::NotOnCalendar { year, day });
+    }
+
+    let day_file = day_file(src, year, day);
+
+    if day_file.exists() {
+        return Err(ScaffoldError::AlreadyExists(day_file));
+    }
+
+    Ok(())
+}
+
+/// Generates `y{year}/days/day{day}.rs` under `src` and wires it into the day list,
+/// creating the year's modules first if it's the first day of the year.
+/// Returns every file that was written.
+///
+/// # Errors
+/// This function errors if the day already exists or the module files can't be updated.
+pub fn scaffold_day(
+    src: &Path,
+    year: u64,
+    day: u64,
+    title: &str,
+    examples: &[Example],
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    check_new_day(src, year, day)?;
+
+    let day_file = day_file(src, year, day);
+    let days_file = src.join(format!("solves/y{year}/days.rs"));
+
+    let mut written = vec![];
+
+    if days_file.exists() {
+        edit(&days_file, |days| register_day(days, day))?;
+    } else {
+        let year_file = src.join(format!("solves/y{year}.rs"));
+        let solves_file = src.join("solves.rs");
+
+        edit(&solves_file, |solves| register_year(solves, year))?;
+        written.push(solves_file);
+
+        create(&year_file, &year_template(year))?;
+        written.push(year_file);
+
+        create(&days_file, &days_template(&[day]))?;
+    }
+
+    written.push(days_file);
+
+    create(&day_file, &day_template(year, day, title, examples))?;
+    written.push(day_file);
+
+    Ok(written)
+}
+
+fn create(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    if let Some(directory) = path.parent() {
+        create_dir_all(directory).map_err(|e| ScaffoldError::io(directory, e))?;
+    }
+
+    write(path, content).map_err(|e| ScaffoldError::io(path, e))
+}
+
+fn edit(
+    path: &Path,
+    change: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), ScaffoldError> {
+    let content = read_to_string(path).map_err(|e| ScaffoldError::io(path, e))?;
+
+    let changed = change(&content).map_err(|expected| ScaffoldError::Unrecognized {
+        path: path.to_path_buf(),
+        expected,
+    })?;
+
+    write(path, changed).map_err(|e| ScaffoldError::io(path, e))
+}
+
+/// Splits `content` around the first `{start}...{end}` after `after`, returning
+/// what's before, the comma separated items in between, and what's after.
+fn split_list<'a>(
+    content: &'a str,
+    after: &str,
+    start: &str,
+    end: &str,
+) -> Option<(&'a str, Vec<&'a str>, &'a str)> {
+    let from = content.find(after)?;
+    let list_start = from + content[from..].find(start)? + start.len();
+    let list_end = list_start + content[list_start..].find(end)?;
+
+    let items = content[list_start..list_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect();
+
+    Some((&content[..list_start], items, &content[list_end..]))
+}
+
+/// Lays out items the way rustfmt does, on one line if they fit and one per line if they don't.
+fn join_list(items: &[String], indent: usize, prefix_width: usize) -> String {
+    let line = items.join(", ");
+
+    if prefix_width + line.len() + 2 <= MAX_WIDTH {
+        return line;
+    }
+
+    let inner = " ".repeat(indent + 4);
+    let items = items.iter().fold(String::new(), |mut items, item| {
+        let _unused_result = writeln!(items, "{inner}{item},");
+        items
+    });
+
+    format!("\n{items}{}", " ".repeat(indent))
+}
+
+fn register_day(days: &str, day: u64) -> Result<String, String> {
+    let (before, items, after) = split_list(days, "register_days!", "(", ")")
+        .ok_or_else(|| String::from("the register_days! list"))?;
+
+    let mut numbers: Vec<u64> = items
+        .iter()
+        .filter_map(|item| item.strip_prefix("day")?.parse().ok())
+        .collect();
+
+    if !numbers.contains(&day) {
+        numbers.push(day);
+    }
+    numbers.sort_unstable();
+
+    let items: Vec<String> = numbers.iter().map(|day| format!("day{day}")).collect();
+
+    Ok(format!(
+        "{before}{}{after}",
+        join_list(&items, 0, "register_days!(".len())
+    ))
+}
+
+fn register_year(solves: &str, year: u64) -> Result<String, String> {
+    let module = format!("y{year}");
+
+    let (before, items, after) = split_list(solves, "pub fn get_years", "vec![", "]")
+        .ok_or_else(|| String::from("the get_years list"))?;
+
+    let mut years: Vec<String> = items.iter().map(ToString::to_string).collect();
+    years.push(format!("{module}::get_{year}_solutions()"));
+    years.sort();
+
+    let solves = format!(
+        "{before}{}{after}",
+        join_list(&years, 4, "    let mut solved = vec![".len())
+    );
+
+    let mut lines: Vec<&str> = solves.lines().collect();
+    let declaration = format!("pub mod {module};");
+
+    let position = lines
+        .iter()
+        .position(|line| {
+            line.strip_prefix("pub mod y")
+                .and_then(|line| line.strip_suffix(';'))
+                .is_some_and(|name| name > &module[1..])
+        })
+        .ok_or_else(|| String::from("pub mod year;"))?;
+    lines.insert(position, &declaration);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn year_template(year: u64) -> String {
+    format!(
+        "use super::year::AdventOfCodeYear;
+
+mod days;
+
+#[must_use]
+pub fn get_{year}_solutions<'a>() -> AdventOfCodeYear<'a> {{
+    AdventOfCodeYear::new({year}, days::get_days())
+}}
+"
+    )
+}
+
+fn days_template(days: &[u64]) -> String {
+    let days: Vec<String> = days.iter().map(|day| format!("day{day}")).collect();
+
+    format!(
+        "use crate::solves::year::register_days;\n\nregister_days!({});\n",
+        join_list(&days, 0, "register_days!(".len())
+    )
+}
+
+/// The tests are ignored until the day is solved, examples with parameters parse with them.
+fn example_test(day: u64, example: &Example) -> Option<String> {
+    let part = example.part.number();
+    let answer = example.answer.as_deref()?;
+
+    if example.params.is_empty() {
+        return Some(format!(
+            "
+#[test]
+#[ignore = \"unsolved\"]
+fn part{part}_test() {{
+    assert_eq!(Day{day}::solve_part{part}({:?}), {answer:?});
+}}
+",
+            example.input
+        ));
+    }
+
+    let overrides = example
+        .params
+        .iter()
+        .fold(String::new(), |mut overrides, (name, value)| {
+            let _unused_result =
+                writeln!(overrides, "    params.set({name:?}, {value:?}).unwrap();");
+            overrides
+        });
+
+    Some(format!(
+        "
+#[test]
+#[ignore = \"unsolved\"]
+fn part{part}_test() {{
+    use crate::solves::params::Params;
+
+    let mut params = Params::defaults(Day{day}::PARAMS);
+{overrides}
+    let parsed = Day{day}::parse_with({:?}, &params).unwrap();
+
+    assert_eq!(Day{day}::part{part}(&parsed).unwrap(), {answer:?});
+}}
+",
+        example.input
+    ))
+}
+
+/// Declares every parameter the examples set, as a number if it looks like one.
+/// The examples' values stand in for the defaults until the real ones are known.
+fn params_declaration(examples: &[&Example]) -> String {
+    let mut params: Vec<String> = vec![];
+    let mut names: Vec<&str> = vec![];
+
+    for (name, value) in examples.iter().flat_map(|example| &example.params) {
+        if names.contains(&name.as_str()) {
+            continue;
+        }
+
+        names.push(name);
+        params.push(value.replace('_', "").parse::<i64>().map_or_else(
+            |_| format!("Param::text({name:?}, \"Set by the examples\", {value:?})"),
+            |number| format!("Param::number({name:?}, \"Set by the examples\", {number})"),
+        ));
+    }
+
+    if params.is_empty() {
+        return String::new();
+    }
+
+    let prefix = "    const PARAMS: &'static [Param] = &[";
+
+    format!("{prefix}{}];\n", join_list(&params, 4, prefix.len()))
+}
+
+fn day_template(year: u64, day: u64, title: &str, examples: &[Example]) -> String {
+    let examples: Vec<&Example> = [Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| examples.iter().find(|example| example.part == part))
+        .collect();
+
+    let tests: String = examples
+        .iter()
+        .filter_map(|example| example_test(day, example))
+        .collect();
+
+    let params = params_declaration(&examples);
+    let params_import = if params.is_empty() {
+        ""
+    } else {
+        "\n    params::Param,"
+    };
+
+    format!(
+        "use crate::solves::{{{params_import}
+    solution::{{Answer, Solution, SolveError}},
+    year::AdventOfCodeDay,
+}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Parsed = Vec<String>;
+
+    const YEAR: u64 = {year};
+    const DAY: u64 = {day};
+{params}
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {{
+        todo!()
+    }}
+
+    fn part2(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {{
+        todo!()
+    }}
+}}
+{tests}
+// Switch to `part1_only` once part 1 is solved and to `new` once both are,
+// then stop ignoring their tests.
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::parse_only::<Day{day}>({title:?});
+"
+    )
+}
+
+#[test]
+fn should_register_days_in_order() {
+    assert_eq!(
+        register_day("use x;\n\nregister_days!(day1, day3);\n", 2).unwrap(),
+        "use x;\n\nregister_days!(day1, day2, day3);\n"
+    );
+
+    let many = register_day(&days_template(&(1..=20).collect::<Vec<_>>()), 21).unwrap();
+    assert!(many.contains("register_days!(\n    day1,\n"));
+    assert!(many.ends_with("    day21,\n);\n"));
+    assert_eq!(
+        register_day(&many, 22).unwrap().matches("day21,").count(),
+        1
+    );
+
+    assert!(register_day("mod day1;", 2).is_err());
+}
+
+#[test]
+fn should_scaffold_days_and_years() {
+    let src = std::env::temp_dir().join("rusty_xmas_scaffold");
+    let _unused_result = std::fs::remove_dir_all(&src);
+
+    create(
+        &src.join("solves.rs"),
+        "pub mod unlock;\npub mod y2019;\npub mod year;\n\n#[must_use]\npub fn get_years<'a>() -> Vec<AdventOfCodeYear<'a>> {\n    let mut solved = vec![y2019::get_2019_solutions()];\n}\n",
+    )
+    .unwrap();
+
+    let examples = [
+        Example {
+            part: Part::One,
+            input: String::from("a\n\"b\""),
+            answer: Some(String::from("2")),
+            params: vec![],
+        },
+        Example {
+            part: Part::Two,
+            input: String::from("a"),
+            answer: Some(String::from("7")),
+            params: vec![(String::from("steps"), String::from("10"))],
+        },
+    ];
+
+    let written = scaffold_day(&src, 2020, 3, "Toboggan Trajectory", &examples).unwrap();
+    assert_eq!(written.len(), 4);
+
+    let solves = read_to_string(src.join("solves.rs")).unwrap();
+    assert!(solves.contains("pub mod y2019;\npub mod y2020;\npub mod year;"));
+    assert!(solves.contains("vec![y2019::get_2019_solutions(), y2020::get_2020_solutions()];"));
+
+    let day = read_to_string(src.join("solves/y2020/days/day3.rs")).unwrap();
+    assert!(day.contains("const DAY: u64 = 3;"));
+    assert!(day.contains(r#"Day3::solve_part1("a\n\"b\""), "2");"#));
+    assert!(day.contains(r#"params.set("steps", "10").unwrap();"#));
+    assert!(day.contains(r#"Day3::parse_with("a", &params).unwrap();"#));
+    assert!(day.contains(r#"assert_eq!(Day3::part2(&parsed).unwrap(), "7");"#));
+    assert!(day.contains(r#"parse_only::<Day3>("Toboggan Trajectory")"#));
+    assert_eq!(day.matches("#[ignore = \"unsolved\"]").count(), 2);
+    assert!(day.contains("    params::Param,\n"));
+    assert!(day.contains(
+        r#"const PARAMS: &'static [Param] = &[Param::number("steps", "Set by the examples", 10)];"#
+    ));
+
+    scaffold_day(&src, 2020, 1, "Report Repair", &[]).unwrap();
+    let day = read_to_string(src.join("solves/y2020/days/day1.rs")).unwrap();
+    assert!(!day.contains("Param"));
+    assert_eq!(
+        read_to_string(src.join("solves/y2020/days.rs")).unwrap(),
+        "use crate::solves::year::register_days;\n\nregister_days!(day1, day3);\n"
+    );
+
+    assert!(matches!(
+        scaffold_day(&src, 2020, 1, "Report Repair", &[]),
+        Err(ScaffoldError::AlreadyExists(_))
+    ));
+    assert!(matches!(
+        scaffold_day(&src, 2020, 26, "", &[]),
+        Err(ScaffoldError::NotOnCalendar { .. })
+    ));
+
+    std::fs::remove_dir_all(src).unwrap();
+}
+
+#[test]
+fn should_find_the_sources_from_inside_a_checkout() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    assert_eq!(
+        find_source_root(&manifest.join("src/solves/y2019")).unwrap(),
+        manifest.join("src")
+    );
+    assert!(matches!(
+        find_source_root(&std::env::temp_dir()),
+        Err(ScaffoldError::NoSources(_))
+    ));
+}
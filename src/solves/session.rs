@@ -0,0 +1,232 @@
+use std::{
+    env,
+    fmt::Display,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use super::{client::AocClient, data_dir::DataDir, error::DataError, html};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionSource {
+    Environment,
+    File(PathBuf),
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Environment => write!(f, "${SESSION_VAR}"),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub token: String,
+    pub source: SessionSource,
+}
+
+/// `$XDG_CONFIG_HOME/rusty_xmas`, or `~/.config/rusty_xmas`.
+#[must_use]
+pub fn config_dir() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join("rusty_xmas"))
+}
+
+/// `session.txt` in the [`config_dir`].
+#[must_use]
+pub fn config_path() -> Option<PathBuf> {
+    Some(config_dir()?.join("session.txt"))
+}
+
+/// Looks for a session key in `$AOC_SESSION`, then the config file, then `session.txt`
+/// in the data directory.
+///
+/// # Errors
+/// This function errors if none of them hold a session key.
+pub fn find_session() -> Result<Session, DataError> {
+    find_session_for(DataDir::current())
+}
+
+/// Named profiles only use their own `session.txt`, the default profile looks
+/// everywhere [`find_session`] does.
+///
+/// # Errors
+/// This function errors if the profile has no session key.
+pub fn find_session_for(data: &DataDir) -> Result<Session, DataError> {
+    if data.profile().is_some() {
+        return find_session_in(None, &[data.session_file()]);
+    }
+
+    let mut files = vec![];
+    files.extend(config_path());
+    files.push(data.session_file());
+
+    find_session_in(env::var(SESSION_VAR).ok(), &files)
+}
+
+/// # Errors
+/// This function errors if neither `variable` nor any of `files` hold a session key.
+pub fn find_session_in(
+    variable: Option<String>,
+    files: &[impl AsRef<Path>],
+) -> Result<Session, DataError> {
+    if let Some(token) = variable.map(|token| token.trim().to_string()) {
+        if !token.is_empty() {
+            return Ok(Session {
+                token,
+                source: SessionSource::Environment,
+            });
+        }
+    }
+
+    files
+        .iter()
+        .find_map(|path| {
+            let token = read_to_string(path).ok()?.trim().to_string();
+
+            (!token.is_empty()).then(|| Session {
+                token,
+                source: SessionSource::File(path.as_ref().to_path_buf()),
+            })
+        })
+        .ok_or(DataError::MissingSession)
+}
+
+/// Advent of Code answers requests that need a login with a 400, or with the login page.
+#[must_use]
+pub fn is_logged_out(status: u16, body: &str) -> bool {
+    status == 400 || body.contains("Please log in") || body.contains("/auth/login")
+}
+
+/// Asks Advent of Code who the session belongs to.
+///
+/// # Errors
+/// This function errors if the request fails or the session isn't logged in.
+pub fn check_session(client: &AocClient) -> Result<String, DataError> {
+    let (status, page) = client.get_with_status("/settings")?;
+
+    if is_logged_out(status, &page) {
+        return Err(DataError::ExpiredSession);
+    }
+
+    let root = html::parse(&page);
+    let user = root
+        .find(&|element| element.name == "div" && element.has_class("user"))
+        .and_then(|user| {
+            user.children.iter().find_map(|child| match child {
+                html::Node::Text(text) if !text.trim().is_empty() => Some(text.trim().to_string()),
+                _ => None,
+            })
+        });
+
+    user.ok_or(DataError::ExpiredSession)
+}
+
+#[test]
+fn should_prefer_environment_then_files_in_order() {
+    let root = env::temp_dir().join("rusty_xmas_session_lookup");
+    std::fs::create_dir_all(&root).unwrap();
+
+    let config = root.join("config.txt");
+    let data = root.join("data.txt");
+    std::fs::write(&config, "  \n").unwrap();
+    std::fs::write(&data, "from-data\n").unwrap();
+
+    assert_eq!(
+        find_session_in(Some(String::from("from-env")), &[&config, &data]).unwrap(),
+        Session {
+            token: String::from("from-env"),
+            source: SessionSource::Environment
+        }
+    );
+
+    assert_eq!(
+        find_session_in(None, &[&config, &data]).unwrap(),
+        Session {
+            token: String::from("from-data"),
+            source: SessionSource::File(data.clone())
+        }
+    );
+
+    std::fs::write(&config, "from-config").unwrap();
+    assert_eq!(
+        find_session_in(Some(String::new()), &[&config, &data])
+            .unwrap()
+            .token,
+        "from-config"
+    );
+
+    assert!(matches!(
+        find_session_in(None, &[root.join("missing.txt")]),
+        Err(DataError::MissingSession)
+    ));
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn should_report_logged_in_user() {
+    use super::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/settings",
+        200,
+        "<header><div class=\"user\">pehdfms <span class=\"star-count\">14*</span></div></header>",
+    );
+
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+
+    assert_eq!(check_session(&client).unwrap(), "pehdfms");
+}
+
+#[test]
+fn should_detect_expired_session() {
+    use super::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/settings",
+        200,
+        "<p>To play, please identify yourself via one of these services:</p><a href=\"/auth/login\">[Log In]</a>",
+    );
+
+    let client = AocClient::new(&server.url(), "expired").unwrap();
+
+    assert!(matches!(
+        check_session(&client),
+        Err(DataError::ExpiredSession)
+    ));
+}
+
+#[test]
+fn should_only_use_profile_session_file() {
+    let data = DataDir::temporary("profile_session").with_profile(Some("alice"));
+
+    assert!(matches!(
+        find_session_for(&data),
+        Err(DataError::MissingSession)
+    ));
+
+    std::fs::create_dir_all(data.session_file().parent().unwrap()).unwrap();
+    std::fs::write(data.session_file(), "alice-session\n").unwrap();
+
+    assert_eq!(
+        find_session_for(&data).unwrap(),
+        Session {
+            token: String::from("alice-session"),
+            source: SessionSource::File(data.session_file())
+        }
+    );
+
+    std::fs::remove_dir_all(data.root()).unwrap();
+}
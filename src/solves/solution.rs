@@ -0,0 +1,310 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use super::{
+    params::{Param, Params},
+    year::Part,
+};
+
+/// What a part evaluates to. Numbers and text are submitted as they're displayed,
+/// grids are letters drawn with pixels that have to be read by a human first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Whether the answer can be sent to Advent of Code as is.
+    #[must_use]
+    pub const fn is_submittable(&self) -> bool {
+        !matches!(self, Self::Grid(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Grid(rows) => write!(f, "\n{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(n: $number) -> Self {
+                    Self::Number(i128::from(n))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Number(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Number(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Number(n) => other.parse() == Ok(*n),
+            Self::Text(text) => text == other,
+            Self::Grid(rows) => rows.join("\n") == other.trim_start_matches('\n'),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input doesn't fit the puzzle, for solutions that check instead of panicking.
+    Invalid(String),
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+}
+
+impl SolveError {
+    #[must_use]
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::Invalid(message.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(message) => write!(f, "Invalid input: {message}"),
+            Self::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "Panicked at {location}: {message}"),
+            Self::Panicked {
+                message,
+                location: None,
+            } => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// What variants are compared against, the part itself, so no variant can be called that.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Another way of solving a part from the same parsed input, like a brute force
+/// kept around next to an optimized solution.
+pub struct Variant<S: Solution> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S::Parsed) -> Result<Answer, SolveError>,
+}
+
+/// A day's solution. The input is parsed once, then both parts borrow the result.
+pub trait Solution {
+    /// Owned, so it can be kept around between parts without holding on to the input.
+    type Parsed: 'static;
+
+    const YEAR: u64;
+    /// Where the solution goes on the calendar, from 1 to the length of the event, see
+    /// [`days_in_event`](super::catalog::days_in_event).
+    const DAY: u64;
+
+    /// Constants of the puzzle that can be overridden, handed to [`Solution::parse_with`].
+    const PARAMS: &'static [Param] = &[];
+
+    /// # Errors
+    /// This function errors if `input` doesn't fit the puzzle.
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError>;
+
+    /// Like [`Solution::parse`], with `params` in place of the defaults of [`Solution::PARAMS`].
+    /// Days with parameters implement this, keep what the parts need in `Parsed` and
+    /// have `parse` call it with [`Params::defaults`].
+    ///
+    /// # Errors
+    /// This function errors if `input` doesn't fit the puzzle.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Parsed, SolveError> {
+        Self::parse(input)
+    }
+
+    /// # Errors
+    /// This function errors if the parsed input has no answer.
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    /// # Errors
+    /// This function errors if the parsed input has no answer.
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    /// Other ways of solving the parts, compared against [`Solution::part1`] and [`Solution::part2`].
+    #[must_use]
+    fn variants() -> Vec<Variant<Self>>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    /// Parses `input` and solves part 1, mostly useful in tests.
+    ///
+    /// # Panics
+    /// If parsing or solving errors.
+    #[must_use]
+    fn solve_part1(input: &str) -> Answer {
+        Self::parse(input)
+            .and_then(|parsed| Self::part1(&parsed))
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parses `input` and solves part 2, mostly useful in tests.
+    ///
+    /// # Panics
+    /// If parsing or solving errors.
+    #[must_use]
+    fn solve_part2(input: &str) -> Answer {
+        Self::parse(input)
+            .and_then(|parsed| Self::part2(&parsed))
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+thread_local! {
+    /// How many [`catch_panic`] calls are running on this thread, panics outside of them
+    /// are reported as usual.
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    /// Where the last panic caught on this thread happened.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) == 0 {
+                return default_hook(info);
+            }
+
+            LOCATION.with(|cell| cell.replace(info.location().map(ToString::to_string)));
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("Box<dyn Any>"))
+}
+
+/// Runs `f`, turning a panic into [`SolveError::Panicked`] instead of unwinding further.
+/// The panic isn't printed, so it doesn't end up in the middle of a menu.
+///
+/// # Errors
+/// This function errors if `f` errors or panics.
+pub fn catch_panic<T>(f: impl FnOnce() -> Result<T, SolveError>) -> Result<T, SolveError> {
+    install_hook();
+
+    LOCATION.with(|cell| cell.replace(None));
+    CATCHING.with(|catching| catching.set(catching.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(catching.get() - 1));
+
+    result.unwrap_or_else(|payload| {
+        Err(SolveError::Panicked {
+            message: panic_message(payload.as_ref()),
+            location: LOCATION.with(RefCell::take),
+        })
+    })
+}
+
+#[test]
+fn should_display_answers() {
+    assert_eq!(Answer::from(42_u64).to_string(), "42");
+    assert_eq!(Answer::from(-7_i64), "-7");
+    assert_eq!(Answer::from("ABC"), "ABC");
+
+    let grid = Answer::Grid(vec![String::from("#.#"), String::from(".#.")]);
+    assert_eq!(grid.to_string(), "\n#.#\n.#.");
+    assert!(!grid.is_submittable());
+}
+
+#[test]
+fn should_catch_panics_with_their_location() {
+    let line = line!() + 1;
+    let result: Result<(), _> = catch_panic(|| panic!("bad input {}", 42));
+
+    let Err(SolveError::Panicked { message, location }) = result else {
+        panic!("expected a caught panic, got {result:?}");
+    };
+
+    assert_eq!(message, "bad input 42");
+    assert!(location
+        .unwrap()
+        .starts_with(&format!("src/solves/solution.rs:{line}:")));
+
+    assert_eq!(
+        catch_panic(|| Err::<(), _>(SolveError::invalid("empty"))),
+        Err(SolveError::Invalid(String::from("empty")))
+    );
+    assert_eq!(catch_panic(|| Ok(1)), Ok(1));
+}
+
+#[test]
+fn should_keep_panic_locations_per_thread() {
+    let first = std::thread::spawn(|| (line!(), catch_panic::<()>(|| panic!("first"))));
+    let second = std::thread::spawn(|| (line!(), catch_panic::<()>(|| panic!("second"))));
+
+    for thread in [first, second] {
+        let (line, result) = thread.join().unwrap();
+
+        let Err(SolveError::Panicked { location, .. }) = result else {
+            panic!("the panic should have been caught");
+        };
+
+        assert!(location
+            .unwrap()
+            .starts_with(&format!("src/solves/solution.rs:{line}:")));
+    }
+}
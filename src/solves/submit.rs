@@ -0,0 +1,214 @@
+use std::{error::Error, fmt::Display, time::Duration};
+
+use super::{
+    client::AocClient, error::DataError, ledger::Rejection, session::is_logged_out, year::Part,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited {
+        wait: Duration,
+    },
+    AlreadySolved,
+    /// The reply didn't match any known message, kept around so it can be shown as is.
+    Unrecognized(String),
+}
+
+impl Verdict {
+    #[must_use]
+    pub fn parse(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            Self::Correct
+        } else if html.contains("your answer is too high") {
+            Self::TooHigh
+        } else if html.contains("your answer is too low") {
+            Self::TooLow
+        } else if html.contains("That's not the right answer") {
+            Self::Wrong
+        } else if html.contains("You gave an answer too recently") {
+            Self::RateLimited {
+                wait: parse_wait(html).unwrap_or_default(),
+            }
+        } else if html.contains("You don't seem to be solving the right level") {
+            Self::AlreadySolved
+        } else {
+            Self::Unrecognized(html.to_string())
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            Self::TooLow => write!(f, "That's not the right answer, it's too low."),
+            Self::Wrong => write!(f, "That's not the right answer."),
+            Self::RateLimited { wait } => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Self::AlreadySolved => write!(f, "This part has already been solved."),
+            Self::Unrecognized(_) => write!(f, "Couldn't understand the response to this answer."),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Rejected(Rejection),
+    Data(DataError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rejected(rejection) => write!(f, "{rejection}"),
+            Self::Data(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Rejected(rejection) => Some(rejection),
+            Self::Data(e) => Some(e),
+        }
+    }
+}
+
+impl From<Rejection> for SubmitError {
+    fn from(rejection: Rejection) -> Self {
+        Self::Rejected(rejection)
+    }
+}
+
+impl From<DataError> for SubmitError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+/// Reads durations like "You have 1m 23s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    html[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len() - 1);
+            let number: u64 = number.parse().ok()?;
+
+            match unit {
+                "h" => Some(number * 60 * 60),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// # Errors
+/// This function errors if the answer couldn't be sent to Advent of Code.
+pub fn submit(
+    client: &AocClient,
+    year: u64,
+    day: u64,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, DataError> {
+    let level = part.number().to_string();
+    let (status, response) = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    if is_logged_out(status, &response) {
+        return Err(DataError::ExpiredSession);
+    }
+
+    Ok(Verdict::parse(&response))
+}
+
+#[test]
+fn should_parse_verdicts() {
+    assert_eq!(
+        Verdict::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+        Verdict::Correct
+    );
+
+    assert_eq!(
+        Verdict::parse("<p>That's not the right answer; your answer is too high.</p>"),
+        Verdict::TooHigh
+    );
+
+    assert_eq!(
+        Verdict::parse("<p>That's not the right answer; your answer is too low.</p>"),
+        Verdict::TooLow
+    );
+
+    assert_eq!(
+        Verdict::parse("<p>That's not the right answer.  If you're stuck, make sure...</p>"),
+        Verdict::Wrong
+    );
+
+    assert_eq!(
+        Verdict::parse(
+            "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+        ),
+        Verdict::AlreadySolved
+    );
+
+    assert!(matches!(
+        Verdict::parse("<p>Something else entirely</p>"),
+        Verdict::Unrecognized(_)
+    ));
+}
+
+#[test]
+fn should_parse_rate_limit_wait() {
+    assert_eq!(
+        Verdict::parse("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.</p>"),
+        Verdict::RateLimited {
+            wait: Duration::from_secs(37)
+        }
+    );
+
+    assert_eq!(
+        Verdict::parse("<p>You gave an answer too recently. You have 4m 2s left to wait.</p>"),
+        Verdict::RateLimited {
+            wait: Duration::from_secs(242)
+        }
+    );
+}
+
+#[test]
+fn should_post_answer_with_level() {
+    use super::mock_server::MockServer;
+
+    let server = MockServer::start().unwrap();
+    server.respond(
+        "/2019/day/4/answer",
+        200,
+        "<article><p>That's the right answer!</p></article>",
+    );
+
+    let client = AocClient::new(&server.url(), "secret").unwrap();
+    let verdict = submit(&client, 2019, 4, Part::Two, "1234").unwrap();
+
+    assert_eq!(verdict, Verdict::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].body, "level=2&answer=1234");
+    assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+}
@@ -0,0 +1,190 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use super::unlock::Clock;
+
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Keeps at least `interval` between requests, even across runs, by storing
+/// the time of the last request in a file.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+    clock: Box<dyn Clock>,
+}
+
+impl Throttle {
+    pub fn new(path: impl AsRef<Path>, interval: Duration, clock: Box<dyn Clock>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            interval,
+            clock,
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn last_request(&self) -> Option<Duration> {
+        let millis = read_to_string(&self.path).ok()?.trim().parse().ok()?;
+
+        Some(Duration::from_millis(millis))
+    }
+
+    /// Time left before another request is allowed.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        let now = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        self.last_request()
+            .map(|last| (last + self.interval).saturating_sub(now))
+            .unwrap_or_default()
+    }
+
+    /// Sleeps until another request is allowed, then marks one as sent.
+    ///
+    /// # Errors
+    /// This function errors if the time of this request can't be stored.
+    pub fn wait(&self) -> Result<(), io::Error> {
+        let remaining = self.remaining();
+
+        if !remaining.is_zero() {
+            self.clock.sleep(remaining);
+        }
+
+        let now = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write(&self.path, now.as_millis().to_string())
+    }
+}
+
+/// Appends one tab separated line per outgoing request.
+pub struct RequestLog {
+    path: PathBuf,
+    clock: Box<dyn Clock>,
+}
+
+impl RequestLog {
+    pub fn new(path: impl AsRef<Path>, clock: Box<dyn Clock>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            clock,
+        }
+    }
+
+    /// # Errors
+    /// This function errors if the log can't be written to.
+    pub fn record(&self, method: &str, url: &str, status: Option<u16>) -> Result<(), io::Error> {
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        let timestamp = self
+            .clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let status = status.map_or_else(|| String::from("-"), |status| status.to_string());
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{timestamp}\t{method}\t{url}\t{status}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        rc::Rc,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use super::{RequestLog, Throttle};
+    use crate::solves::unlock::{Clock, ManualClock};
+
+    struct SharedClock(Rc<ManualClock>);
+
+    impl Clock for SharedClock {
+        fn now(&self) -> SystemTime {
+            self.0.now()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.sleep(duration);
+        }
+    }
+
+    #[test]
+    fn should_space_out_requests() {
+        let root = std::env::temp_dir().join("rusty_xmas_throttle");
+        let _unused_result = std::fs::remove_dir_all(&root);
+
+        let start = UNIX_EPOCH + Duration::from_secs(1_000);
+        let clock = Rc::new(ManualClock::new(start));
+        let throttle = Throttle::new(
+            root.join("throttle.txt"),
+            Duration::from_secs(5),
+            Box::new(SharedClock(Rc::clone(&clock))),
+        );
+
+        assert_eq!(throttle.remaining(), Duration::ZERO);
+        throttle.wait().unwrap();
+        assert_eq!(clock.now(), start);
+
+        clock.sleep(Duration::from_secs(2));
+        assert_eq!(throttle.remaining(), Duration::from_secs(3));
+
+        throttle.wait().unwrap();
+        assert_eq!(clock.now(), start + Duration::from_secs(5));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn should_append_requests_to_log() {
+        let root = std::env::temp_dir().join("rusty_xmas_request_log");
+        let _unused_result = std::fs::remove_dir_all(&root);
+
+        let clock = ManualClock::new(UNIX_EPOCH + Duration::from_secs(42));
+        let log = RequestLog::new(root.join("requests.log"), Box::new(clock));
+
+        log.record(
+            "GET",
+            "https://adventofcode.com/2019/day/1/input",
+            Some(200),
+        )
+        .unwrap();
+        log.record("POST", "https://adventofcode.com/2019/day/1/answer", None)
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(root.join("requests.log")).unwrap(),
+            "42\tGET\thttps://adventofcode.com/2019/day/1/input\t200\n42\tPOST\thttps://adventofcode.com/2019/day/1/answer\t-\n"
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    cell::Cell,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::catalog::days_in_event;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Puzzles unlock at midnight US Eastern, which is always UTC-5 in December.
+const UNLOCK_OFFSET: Duration = Duration::from_secs(5 * 60 * 60);
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that only moves when slept on, for testing time dependent code.
+pub struct ManualClock {
+    now: Cell<SystemTime>,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub const fn new(now: SystemTime) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+/// Days since the Unix epoch for a date in the proleptic Gregorian calendar.
+#[must_use]
+pub const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// (year, month, day) for a number of days since the Unix epoch.
+#[must_use]
+pub const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// # Panics
+/// If `year` or `day` are too large to be a date.
+#[must_use]
+pub fn unlock_time(year: u64, day: u64) -> SystemTime {
+    let year = i64::try_from(year).expect("Year should fit in an i64");
+    let day = i64::try_from(day).expect("Day should fit in an i64");
+
+    let days = days_from_civil(year, 12, day);
+    let midnight = Duration::from_secs(days.unsigned_abs() * SECONDS_PER_DAY);
+
+    if days >= 0 {
+        UNIX_EPOCH + midnight + UNLOCK_OFFSET
+    } else {
+        UNIX_EPOCH - midnight + UNLOCK_OFFSET
+    }
+}
+
+/// Time left until the puzzle unlocks, `None` if it already has.
+#[must_use]
+pub fn time_until_unlock(clock: &dyn Clock, year: u64, day: u64) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(clock.now())
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+#[must_use]
+pub fn is_unlocked(clock: &dyn Clock, year: u64, day: u64) -> bool {
+    time_until_unlock(clock, year, day).is_none()
+}
+
+/// The (year, month, day) it is in US Eastern time, the calendar puzzles follow.
+#[must_use]
+pub fn eastern_date(clock: &dyn Clock) -> (u64, u64, u64) {
+    let seconds = clock
+        .now()
+        .duration_since(UNIX_EPOCH + UNLOCK_OFFSET)
+        .unwrap_or_default()
+        .as_secs();
+
+    civil_from_days(seconds / SECONDS_PER_DAY)
+}
+
+/// The next puzzle to unlock, as (year, day).
+#[must_use]
+pub fn next_unlock(clock: &dyn Clock) -> (u64, u64) {
+    let (year, month, day) = eastern_date(clock);
+
+    match (month, day) {
+        (12, day) if day < days_in_event(year) => (year, day + 1),
+        (12, _) => (year + 1, 1),
+        _ => (year, 1),
+    }
+}
+
+/// Sleeps until the puzzle unlocks, calling `on_tick` with the time left about once a second.
+pub fn wait_for_unlock(clock: &dyn Clock, year: u64, day: u64, mut on_tick: impl FnMut(Duration)) {
+    while let Some(remaining) = time_until_unlock(clock, year, day) {
+        on_tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+#[test]
+fn should_unlock_at_midnight_eastern() {
+    let timestamp = |time: SystemTime| time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+    assert_eq!(timestamp(unlock_time(2019, 1)), 1_575_176_400);
+    assert_eq!(timestamp(unlock_time(2025, 12)), 1_765_515_600);
+}
+
+#[test]
+fn should_roundtrip_civil_dates() {
+    for days in [0, 59, 60, 365, 10_957, 18_231, 20_424] {
+        let (year, month, day) = civil_from_days(days);
+        let signed = |n: u64| i64::try_from(n).unwrap();
+
+        assert_eq!(
+            days_from_civil(signed(year), signed(month), signed(day)),
+            signed(days)
+        );
+    }
+
+    assert_eq!(days_from_civil(1969, 12, 31), -1);
+
+    assert_eq!(civil_from_days(18_231), (2019, 12, 1));
+}
+
+#[test]
+fn should_know_the_next_unlock() {
+    let before = ManualClock::new(unlock_time(2019, 1) - Duration::from_secs(1));
+    assert_eq!(next_unlock(&before), (2019, 1));
+    assert!(!is_unlocked(&before, 2019, 1));
+
+    let during = ManualClock::new(unlock_time(2019, 7));
+    assert_eq!(next_unlock(&during), (2019, 8));
+    assert!(is_unlocked(&during, 2019, 7));
+
+    let after = ManualClock::new(unlock_time(2019, 25) + Duration::from_secs(60));
+    assert_eq!(next_unlock(&after), (2020, 1));
+
+    let short = ManualClock::new(unlock_time(2025, 12) + Duration::from_secs(60));
+    assert_eq!(next_unlock(&short), (2026, 1));
+}
+
+#[test]
+fn should_tick_until_unlocked() {
+    let clock = ManualClock::new(unlock_time(2019, 1) - Duration::from_millis(2500));
+    let mut ticks = vec![];
+
+    wait_for_unlock(&clock, 2019, 1, |remaining| {
+        ticks.push(remaining.as_millis());
+    });
+
+    assert_eq!(ticks, vec![2500, 1500, 500]);
+    assert!(is_unlocked(&clock, 2019, 1));
+}
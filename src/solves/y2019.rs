@@ -4,8 +4,5 @@ mod days;
 
 #[must_use]
 pub fn get_2019_solutions<'a>() -> AdventOfCodeYear<'a> {
-    AdventOfCodeYear {
-        year: 2019,
-        days: days::get_days(),
-    }
+    AdventOfCodeYear::new(2019, days::get_days())
 }
@@ -1,60 +1,117 @@
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    params::{Param, Params},
+    solution::{Answer, Solution, SolveError},
+    year::AdventOfCodeDay,
+};
 
-const fn get_fuel(mass: i64) -> i64 {
-    mass / 3 - 2
+pub struct Modules {
+    masses: Vec<i64>,
+    equation: FuelEquation,
 }
 
-fn part1(data: &str) -> String {
-    let result: i64 = data
-        .split_whitespace()
-        .map(|n| {
-            let mass: i64 = n.parse().expect("All splits should parse as number.");
-            get_fuel(mass)
-        })
-        .sum();
-
-    result.to_string()
+/// Fuel is `mass / divisor - overhead`.
+#[derive(Clone, Copy)]
+struct FuelEquation {
+    divisor: i64,
+    overhead: i64,
 }
 
-fn part2(data: &str) -> String {
-    let result: i64 = data
-        .split_whitespace()
-        .map(|n| {
-            let mut mass: i64 = n.parse().expect("All splits should parse as number.");
-            let mut total_cost = 0;
-
-            while mass > 0 {
-                let result = get_fuel(mass);
-                if result < 0 {
-                    break;
-                }
-
-                total_cost += result;
-                mass = result;
+impl FuelEquation {
+    const fn get_fuel(self, mass: i64) -> i64 {
+        mass / self.divisor - self.overhead
+    }
+
+    const fn total_fuel(self, mut mass: i64) -> i64 {
+        let mut total_cost = 0;
+
+        while mass > 0 {
+            let result = self.get_fuel(mass);
+            if result < 0 {
+                break;
             }
 
-            total_cost
-        })
-        .sum();
+            total_cost += result;
+            mass = result;
+        }
 
-    result.to_string()
+        total_cost
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Modules;
+
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 1;
+
+    const PARAMS: &'static [Param] = &[
+        Param::number("divisor", "Mass is divided by this", 3),
+        Param::number("overhead", "Then this is subtracted", 2),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Parsed, SolveError> {
+        let divisor = params.number("divisor");
+        if divisor <= 0 {
+            return Err(SolveError::invalid("divisor should be positive"));
+        }
+
+        let masses = input
+            .split_whitespace()
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| SolveError::invalid(format!("{n} isn't a mass")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Modules {
+            masses,
+            equation: FuelEquation {
+                divisor,
+                overhead: params.number("overhead"),
+            },
+        })
+    }
+
+    fn part1(modules: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(modules
+            .masses
+            .iter()
+            .map(|mass| modules.equation.get_fuel(*mass))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(modules: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(modules
+            .masses
+            .iter()
+            .map(|mass| modules.equation.total_fuel(*mass))
+            .sum::<i64>()
+            .into())
+    }
 }
 
 #[test]
 fn part1_test() {
     // For a mass of 12, divide by 3 and round down to get 4, then subtract 2
     // to get 2.
-    assert_eq!(part1("12"), "2");
+    assert_eq!(Day1::solve_part1("12"), "2");
 
     // For a mass of 14, dividing by 3 and rounding down still yields 4, so
     // the fuel required is also 2.
-    assert_eq!(part1("14"), "2");
+    assert_eq!(Day1::solve_part1("14"), "2");
 
     // For a mass of 1969, the fuel required is 654.
-    assert_eq!(part1("1969"), "654");
+    assert_eq!(Day1::solve_part1("1969"), "654");
 
     //For a mass of 100756, the fuel required is 33583.
-    assert_eq!(part1("100756"), "33583");
+    assert_eq!(Day1::solve_part1("100756"), "33583");
 }
 
 #[test]
@@ -62,22 +119,31 @@ fn part2_test() {
     // A module of mass 14 requires 2 fuel. This fuel requires no further
     // fuel (2 divided by 3 and rounded down is 0, which would call for a
     // negative fuel), so the total fuel required is still just 2.
-    assert_eq!(part2("14"), "2");
+    assert_eq!(Day1::solve_part2("14"), "2");
 
     // At first, a module of mass 1969 requires 654 fuel. Then, this fuel
     // requires 216 more fuel (654 / 3 - 2). 216 then requires 70 more fuel,
     // which requires 21 fuel, which requires 5 fuel, which requires no
     // further fuel. So, the total fuel required for a module of mass 1969 is
     // 654 + 216 + 70 + 21 + 5 = 966.
-    assert_eq!(part2("1969"), "966");
+    assert_eq!(Day1::solve_part2("1969"), "966");
 
     // The fuel required by a module of mass 100756 and its fuel is:
     // 33583 + 11192 + 3728 + 1240 + 411 + 135 + 43 + 12 + 2 = 50346.
-    assert_eq!(part2("100756"), "50346");
+    assert_eq!(Day1::solve_part2("100756"), "50346");
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "The Tyranny of the Rocket Equation",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+#[test]
+fn should_use_overridden_equation() {
+    let mut params = Params::defaults(Day1::PARAMS);
+    params.set("divisor", "2").unwrap();
+    params.set("overhead", "0").unwrap();
+
+    let modules = Day1::parse_with("12", &params).unwrap();
+
+    assert_eq!(Day1::part1(&modules), Ok(Answer::Number(6)));
+    assert_eq!(Day1::part2(&modules), Ok(Answer::Number(10)));
+}
+
+pub const SOLUTION: AdventOfCodeDay =
+    AdventOfCodeDay::new::<Day1>("The Tyranny of the Rocket Equation");
@@ -1,50 +1,82 @@
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    params::{Param, Params},
+    solution::{Answer, Solution, SolveError},
+    year::AdventOfCodeDay,
+};
 
 use super::intcode::Computer;
 
-fn part1(data: &str) -> String {
-    let mut computer = Computer::from_string(data);
+pub struct Program {
+    memory: Vec<i64>,
+    target: i64,
+}
+
+pub struct Day2;
 
-    computer.replace(1, 12);
-    computer.replace(2, 2);
+impl Solution for Day2 {
+    type Parsed = Program;
 
-    computer.run();
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 2;
 
-    computer.read(0).to_string()
-}
+    const PARAMS: &'static [Param] = &[Param::number(
+        "target",
+        "Output part 2 searches a noun and verb for",
+        19_690_720,
+    )];
+
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Parsed, SolveError> {
+        Ok(Program {
+            memory: Computer::parse(input),
+            target: params.number("target"),
+        })
+    }
 
-fn part2(data: &str) -> String {
-    let memory = Computer::parse(data);
+    fn part1(program: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut computer = Computer::from_vec(program.memory.clone());
 
+        computer.replace(1, 12);
+        computer.replace(2, 2);
+
+        computer.run();
+
+        Ok(computer.read(0).into())
+    }
+
+    fn part2(program: &Self::Parsed) -> Result<Answer, SolveError> {
+        find_noun_verb(&program.memory, program.target)
+            .map(Answer::from)
+            .ok_or_else(|| {
+                SolveError::invalid(format!("no noun and verb output {}", program.target))
+            })
+    }
+}
+
+fn find_noun_verb(memory: &[i64], target: i64) -> Option<i64> {
     (0..100)
         .into_par_iter()
         .map(|noun| {
             (0..100)
                 .into_par_iter()
                 .map(|verb| {
-                    let mut computer = Computer::from_vec(memory.clone());
+                    let mut computer = Computer::from_vec(memory.to_vec());
 
                     computer.replace(1, noun);
                     computer.replace(2, verb);
 
                     computer.run();
 
-                    match computer.read(0) {
-                        19_690_720 => Some(100 * noun + verb),
-                        _ => None,
-                    }
+                    (computer.read(0) == target).then_some(100 * noun + verb)
                 })
                 .reduce(|| None, Option::or)
         })
         .reduce(|| None, Option::or)
-        .expect("Valid noun / verb combination should exist!")
-        .to_string()
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "1202 Program Alarm",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::new::<Day2>("1202 Program Alarm");
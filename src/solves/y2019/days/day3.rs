@@ -1,4 +1,7 @@
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    solution::{Answer, Solution, SolveError},
+    year::AdventOfCodeDay,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Point {
@@ -59,7 +62,7 @@ impl Path {
 }
 
 #[derive(Debug)]
-struct Wire {
+pub struct Wire {
     paths: Vec<Path>,
 }
 
@@ -193,50 +196,66 @@ fn parse_wire(path: &str) -> Wire {
     Wire::new(&wire_path)
 }
 
-fn part1(data: &str) -> String {
-    let wires: Vec<Wire> = data.split_whitespace().map(parse_wire).collect();
+pub struct Day3;
 
-    let a = &wires[0];
-    let b = &wires[1];
+impl Solution for Day3 {
+    type Parsed = Vec<Wire>;
 
-    a.closest_intersection(b).to_string()
-}
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 3;
 
-fn part2(data: &str) -> String {
-    let wires: Vec<Wire> = data.split_whitespace().map(parse_wire).collect();
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        let wires: Vec<Wire> = input.split_whitespace().map(parse_wire).collect();
 
-    let a = &wires[0];
-    let b = &wires[1];
+        if wires.len() != 2 {
+            return Err(SolveError::invalid(format!(
+                "expected 2 wires, found {}",
+                wires.len()
+            )));
+        }
+
+        Ok(wires)
+    }
 
-    a.first_intersection(b).to_string()
+    fn part1(wires: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(wires[0].closest_intersection(&wires[1]).into())
+    }
+
+    fn part2(wires: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(wires[0].first_intersection(&wires[1]).into())
+    }
 }
 
 #[test]
 fn part1_test() {
-    assert_eq!(part1("R8,U5,L5,D3\nU7,R6,D4,L4"), "6");
+    assert_eq!(Day3::solve_part1("R8,U5,L5,D3\nU7,R6,D4,L4"), "6");
 
     assert_eq!(
-        part1("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
+        Day3::solve_part1("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
         "159"
     );
 
     assert_eq!(
-        part1("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"),
+        Day3::solve_part1(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
+        ),
         "135"
     );
 }
 
 #[test]
 fn part2_test() {
-    assert_eq!(part2("R8,U5,L5,D3\nU7,R6,D4,L4"), "30");
+    assert_eq!(Day3::solve_part2("R8,U5,L5,D3\nU7,R6,D4,L4"), "30");
 
     assert_eq!(
-        part2("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
+        Day3::solve_part2("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83"),
         "610"
     );
 
     assert_eq!(
-        part2("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"),
+        Day3::solve_part2(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7"
+        ),
         "410"
     );
 }
@@ -244,11 +263,7 @@ fn part2_test() {
 #[test]
 #[should_panic(expected = "Unexpected direction!")]
 fn should_panic_on_unexpected_direction() {
-    part1("J2");
+    let _unused_answer = Day3::solve_part1("J2");
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "Crossed Wires",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::new::<Day3>("Crossed Wires");
@@ -2,28 +2,38 @@ use std::ops::Range;
 
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    params::{Param, Params},
+    solution::{Answer, Solution, SolveError, Variant},
+    year::{AdventOfCodeDay, Part},
+};
+
+pub struct Passwords {
+    range: Range<i64>,
+    digits: u32,
+}
 
-fn get_range(data: &str) -> Range<i64> {
+/// Clamps the range to numbers with `digits` digits.
+fn get_range(data: &str, digits: u32) -> Range<i64> {
     let range: Vec<&str> = data.split('-').collect();
 
     let start = range[0]
         .trim()
         .parse::<i64>()
         .expect("End of range should be int")
-        .max(100_000);
+        .max(10_i64.pow(digits - 1));
 
     let end = range[1]
         .trim()
         .parse::<i64>()
         .expect("End of range should be int")
-        .min(1_000_000);
+        .min(10_i64.pow(digits));
 
     start..end
 }
 
-fn validate_password(password: &str, validate_groups: bool) -> bool {
-    if password.len() != 6 {
+fn validate_password(password: &str, digits: u32, validate_groups: bool) -> bool {
+    if password.len() != digits as usize {
         return false;
     }
 
@@ -69,32 +79,113 @@ fn validate_password(password: &str, validate_groups: bool) -> bool {
     has_adjacent_repeating
 }
 
-fn part1(data: &str) -> String {
-    get_range(data)
-        .into_par_iter()
-        .filter(|item| validate_password(&item.to_string(), false))
-        .count()
-        .to_string()
+/// Digits never decrease, so only those passwords are visited instead of every number in `range`.
+fn count_non_decreasing(passwords: &Passwords, validate_groups: bool) -> usize {
+    fn visit(digits: &mut Vec<i64>, passwords: &Passwords, validate_groups: bool) -> usize {
+        if digits.len() == passwords.digits as usize {
+            let password = digits.iter().fold(0, |n, digit| n * 10 + digit);
+            let has_double = digits.chunk_by(|a, b| a == b).any(|group| {
+                if validate_groups {
+                    group.len() == 2
+                } else {
+                    group.len() >= 2
+                }
+            });
+
+            return usize::from(has_double && passwords.range.contains(&password));
+        }
+
+        let smallest = digits.last().copied().unwrap_or(1);
+
+        (smallest..=9)
+            .map(|digit| {
+                digits.push(digit);
+                let count = visit(digits, passwords, validate_groups);
+                digits.pop();
+                count
+            })
+            .sum()
+    }
+
+    visit(
+        &mut Vec::with_capacity(passwords.digits as usize),
+        passwords,
+        validate_groups,
+    )
 }
 
-fn part2(data: &str) -> String {
-    get_range(data)
-        .into_par_iter()
-        .filter(|item| {
-            if validate_password(&item.to_string(), true) {
-                return true;
-            }
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Passwords;
+
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 4;
+
+    const PARAMS: &'static [Param] = &[Param::number("digits", "Length of a password", 6)];
 
-            false
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Parsed, SolveError> {
+        let digits = u32::try_from(params.number("digits"))
+            .ok()
+            .filter(|digits| (1..=18).contains(digits))
+            .ok_or_else(|| SolveError::invalid("digits should be between 1 and 18"))?;
+
+        Ok(Passwords {
+            range: get_range(input, digits),
+            digits,
         })
-        .count()
-        .to_string()
+    }
+
+    fn part1(passwords: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(passwords
+            .range
+            .clone()
+            .into_par_iter()
+            .filter(|item| validate_password(&item.to_string(), passwords.digits, false))
+            .count()
+            .into())
+    }
+
+    fn part2(passwords: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(passwords
+            .range
+            .clone()
+            .into_par_iter()
+            .filter(|item| {
+                if validate_password(&item.to_string(), passwords.digits, true) {
+                    return true;
+                }
+
+                false
+            })
+            .count()
+            .into())
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "non-decreasing",
+                part: Part::One,
+                solve: |passwords| Ok(count_non_decreasing(passwords, false).into()),
+            },
+            Variant {
+                name: "non-decreasing",
+                part: Part::Two,
+                solve: |passwords| Ok(count_non_decreasing(passwords, true).into()),
+            },
+        ]
+    }
 }
 
 #[test]
 fn should_decline_lt_6_digits() {
     assert!(
-        !validate_password("99999", false),
+        !validate_password("99999", 6, false),
         "should decline less than 6 digit numbers"
     );
 }
@@ -102,7 +193,7 @@ fn should_decline_lt_6_digits() {
 #[test]
 fn should_decline_gt_6_digits() {
     assert!(
-        !validate_password("1000000", false),
+        !validate_password("1000000", 6, false),
         "should decline more than 6 digit numbers"
     );
 }
@@ -110,7 +201,7 @@ fn should_decline_gt_6_digits() {
 #[test]
 fn should_decline_no_adjacent_double() {
     assert!(
-        !validate_password("123456", false),
+        !validate_password("123456", 6, false),
         "should decline when there isn't an identical pair of adjacent digits"
     );
 }
@@ -118,7 +209,7 @@ fn should_decline_no_adjacent_double() {
 #[test]
 fn should_decline_decreasing_digits() {
     assert!(
-        !validate_password("103456", false),
+        !validate_password("103456", 6, false),
         "should decline when digits decrease from left to right"
     );
 }
@@ -126,7 +217,7 @@ fn should_decline_decreasing_digits() {
 #[test]
 fn should_accept_all_doubles() {
     assert!(
-        validate_password("112233", true),
+        validate_password("112233", 6, true),
         "should accept all doubles"
     );
 }
@@ -134,7 +225,7 @@ fn should_accept_all_doubles() {
 #[test]
 fn should_decline_larger_group() {
     assert!(
-        !validate_password("123444", true),
+        !validate_password("123444", 6, true),
         "should decline larger group"
     );
 }
@@ -142,7 +233,7 @@ fn should_decline_larger_group() {
 #[test]
 fn should_accept_combined_doubles() {
     assert!(
-        validate_password("111122", true),
+        validate_password("111122", 6, true),
         "should accept combined doubles"
     );
 }
@@ -150,23 +241,38 @@ fn should_accept_combined_doubles() {
 #[test]
 fn should_accept_valid_passwords() {
     assert!(
-        validate_password("122345", false),
+        validate_password("122345", 6, false),
         "should accept valid password"
     );
 
     assert!(
-        validate_password("111123", false),
+        validate_password("111123", 6, false),
         "should accept valid password"
     );
 
     assert!(
-        validate_password("111111", false),
+        validate_password("111111", 6, false),
         "should accept valid password"
     );
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "Secure Container",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+#[test]
+fn should_agree_with_brute_force() {
+    let mut five_digits = Params::defaults(Day4::PARAMS);
+    five_digits.set("digits", "5").unwrap();
+
+    for params in [Params::defaults(Day4::PARAMS), five_digits] {
+        let passwords = Day4::parse_with("23456-234567", &params).unwrap();
+
+        for variant in Day4::variants() {
+            let expected = match variant.part {
+                Part::One => Day4::part1(&passwords),
+                Part::Two => Day4::part2(&passwords),
+            };
+
+            assert_eq!((variant.solve)(&passwords), expected, "{}", variant.part);
+        }
+    }
+}
+
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::new::<Day4>("Secure Container");
@@ -1,29 +1,50 @@
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    solution::{Answer, Solution, SolveError},
+    year::AdventOfCodeDay,
+};
 
 use super::intcode::Computer;
 
-fn part1(data: &str) -> String {
-    let mut computer = Computer::from_string(data);
+pub struct Day5;
 
-    computer.add_input(1);
+impl Solution for Day5 {
+    type Parsed = Vec<i64>;
 
-    computer.run();
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 5;
 
-    computer.read_outputs().last().unwrap().to_string()
-}
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(Computer::parse(input))
+    }
+
+    fn part1(memory: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut computer = Computer::from_vec(memory.clone());
+
+        computer.add_input(1);
+
+        computer.run();
 
-fn part2(data: &str) -> String {
-    let mut computer = Computer::from_string(data);
+        last_output(&computer)
+    }
 
-    computer.add_input(5);
+    fn part2(memory: &Self::Parsed) -> Result<Answer, SolveError> {
+        let mut computer = Computer::from_vec(memory.clone());
 
-    computer.run();
+        computer.add_input(5);
 
-    computer.read_outputs().last().unwrap().to_string()
+        computer.run();
+
+        last_output(&computer)
+    }
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "Sunny with a Chance of Asteroids",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+fn last_output(computer: &Computer) -> Result<Answer, SolveError> {
+    computer
+        .read_outputs()
+        .last()
+        .map(|output| (*output).into())
+        .ok_or_else(|| SolveError::invalid("the program didn't output anything"))
+}
+
+pub const SOLUTION: AdventOfCodeDay =
+    AdventOfCodeDay::new::<Day5>("Sunny with a Chance of Asteroids");
@@ -1,20 +1,28 @@
 use std::collections::HashMap;
 
-use crate::solves::year::AdventOfCodeDay;
+use crate::solves::{
+    solution::{Answer, Solution, SolveError},
+    year::AdventOfCodeDay,
+};
+
+type Orbits = HashMap<String, Vec<String>>;
 
-fn parse_data(data: &str) -> HashMap<&str, Vec<&str>> {
-    let mut nodes: HashMap<&str, Vec<&str>> = HashMap::new();
+fn parse_data(data: &str) -> Orbits {
+    let mut nodes: Orbits = HashMap::new();
     data.split_whitespace().for_each(|obj| {
         let parts: Vec<&str> = obj.split(')').collect();
 
-        nodes.entry(parts[1]).or_default();
-        nodes.entry(parts[0]).or_default().push(parts[1]);
+        nodes.entry(parts[1].to_string()).or_default();
+        nodes
+            .entry(parts[0].to_string())
+            .or_default()
+            .push(parts[1].to_string());
     });
 
     nodes
 }
 
-fn count_orbits(count: u64, node: &str, map: &HashMap<&str, Vec<&str>>) -> u64 {
+fn count_orbits(count: u64, node: &str, map: &Orbits) -> u64 {
     let children = map.get(node).expect("Node should exist");
 
     count
@@ -24,7 +32,7 @@ fn count_orbits(count: u64, node: &str, map: &HashMap<&str, Vec<&str>>) -> u64 {
             .sum::<u64>()
 }
 
-fn shortest_path(node: &str, map: &HashMap<&str, Vec<&str>>) -> (Option<i64>, Option<i64>) {
+fn shortest_path(node: &str, map: &Orbits) -> (Option<i64>, Option<i64>) {
     let mut result = (None, None);
     map[node].iter().for_each(|node| {
         let child_result = shortest_path(node, map);
@@ -48,23 +56,34 @@ fn shortest_path(node: &str, map: &HashMap<&str, Vec<&str>>) -> (Option<i64>, Op
     result
 }
 
-fn part1(data: &str) -> String {
-    let graph = parse_data(data);
+pub struct Day6;
 
-    count_orbits(0, "COM", &graph).to_string()
-}
+impl Solution for Day6 {
+    type Parsed = Orbits;
+
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 6;
+
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(parse_data(input))
+    }
 
-fn part2(data: &str) -> String {
-    let graph = parse_data(data);
+    fn part1(graph: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(count_orbits(0, "COM", graph).into())
+    }
 
-    let result = shortest_path("COM", &graph);
-    (result.0.unwrap() + result.1.unwrap()).to_string()
+    fn part2(graph: &Self::Parsed) -> Result<Answer, SolveError> {
+        match shortest_path("COM", graph) {
+            (Some(you), Some(santa)) => Ok((you + santa).into()),
+            _ => Err(SolveError::invalid("YOU and SAN should both orbit COM")),
+        }
+    }
 }
 
 #[test]
 fn part1_test() {
     assert_eq!(
-        part1("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L"),
+        Day6::solve_part1("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L"),
         "42"
     );
 }
@@ -72,13 +91,9 @@ fn part1_test() {
 #[test]
 fn part2_test() {
     assert_eq!(
-        part2("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN"),
+        Day6::solve_part2("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN"),
         "4"
     );
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "Universal Orbit Map",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::new::<Day6>("Universal Orbit Map");
@@ -1,6 +1,10 @@
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
-use crate::solves::{y2019::days::intcode::Computer, year::AdventOfCodeDay};
+use crate::solves::{
+    solution::{Answer, Solution, SolveError},
+    y2019::days::intcode::Computer,
+    year::AdventOfCodeDay,
+};
 
 fn non_repeating_permutations(array: &[i64]) -> Vec<Vec<i64>> {
     if array.len() == 2 {
@@ -22,14 +26,33 @@ fn non_repeating_permutations(array: &[i64]) -> Vec<Vec<i64>> {
         .collect()
 }
 
-fn part1(data: &str) -> String {
-    let memory = Computer::parse(data);
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<i64>;
+
+    const YEAR: u64 = 2019;
+    const DAY: u64 = 7;
+
+    fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(Computer::parse(input))
+    }
+
+    fn part1(memory: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(max_thruster_signal(memory).into())
+    }
 
+    fn part2(memory: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(max_feedback_signal(memory).into())
+    }
+}
+
+fn max_thruster_signal(memory: &[i64]) -> i64 {
     non_repeating_permutations(&[0, 1, 2, 3, 4])
         .par_iter()
         .map(|perm| {
             perm.iter().fold(0, |prev, phase_setting| {
-                let mut computer = Computer::from_vec(memory.clone());
+                let mut computer = Computer::from_vec(memory.to_vec());
 
                 computer.add_input(*phase_setting);
                 computer.add_input(prev);
@@ -43,17 +66,14 @@ fn part1(data: &str) -> String {
         })
         .max()
         .expect("Max thruster signal should exist!")
-        .to_string()
 }
 
-fn part2(data: &str) -> String {
-    let memory = Computer::parse(data);
-
+fn max_feedback_signal(memory: &[i64]) -> i64 {
     non_repeating_permutations(&[5, 6, 7, 8, 9])
         .par_iter()
         .map(|perm| {
             let mut amplifiers = vec![];
-            (0..5).for_each(|_| amplifiers.push(Computer::from_vec(memory.clone())));
+            (0..5).for_each(|_| amplifiers.push(Computer::from_vec(memory.to_vec())));
 
             perm.iter().enumerate().for_each(|(idx, phase_setting)| {
                 amplifiers[idx].add_input(*phase_setting);
@@ -81,23 +101,24 @@ fn part2(data: &str) -> String {
         })
         .max()
         .expect("Max thruster signal should exist!")
-        .to_string()
 }
 
 #[test]
 fn part1_test() {
     assert_eq!(
-        part1("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"),
+        Day7::solve_part1("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"),
         "43210"
     );
 
     assert_eq!(
-        part1("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0"),
+        Day7::solve_part1(
+            "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0"
+        ),
         "54321"
     );
 
     assert_eq!(
-        part1(
+        Day7::solve_part1(
             "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0"
         ),
         "65210"
@@ -107,22 +128,18 @@ fn part1_test() {
 #[test]
 fn part2_test() {
     assert_eq!(
-        part2(
+        Day7::solve_part2(
             "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,\n27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5"
         ),
         "139629729"
     );
 
     assert_eq!(
-        part2(
+        Day7::solve_part2(
             "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\n-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\n53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"
         ),
         "18216"
     );
 }
 
-pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay {
-    name: "Amplification Circuit",
-    part1: Some(part1),
-    part2: Some(part2),
-};
+pub const SOLUTION: AdventOfCodeDay = AdventOfCodeDay::new::<Day7>("Amplification Circuit");
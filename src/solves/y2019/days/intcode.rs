@@ -29,6 +29,7 @@ impl Computer {
         }
     }
 
+    #[cfg(test)]
     pub fn from_string(memory: &str) -> Self {
         Self::from_vec(Self::parse(memory))
     }
@@ -56,8 +57,7 @@ impl Computer {
     pub fn parse(memory: &str) -> Vec<i64> {
         memory
             .trim()
-            .replace(' ', "")
-            .replace('\n', "")
+            .replace([' ', '\n'], "")
             .split(',')
             .map(|n| {
                 n.parse()
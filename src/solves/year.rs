@@ -1,4 +1,32 @@
-pub type SolveFunction = Option<fn(data: &str) -> String>;
+use std::{any::Any, fmt::Display};
+
+use super::{
+    catalog::days_in_event,
+    params::{Param, Params},
+    solution::{catch_panic, Answer, Solution, SolveError, DEFAULT_VARIANT},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
 
 #[derive(Debug)]
 pub enum DayProgress {
@@ -7,72 +35,522 @@ pub enum DayProgress {
     FullySolved,
 }
 
+/// A parsed input, whose type only the day that parsed it knows.
+pub struct ParsedInput(Box<dyn Any>);
+
+type ParseFunction = fn(&str, &Params) -> Result<ParsedInput, SolveError>;
+type PartFunction = fn(&ParsedInput) -> Result<Answer, SolveError>;
+type VariantsFunction = fn() -> Vec<(&'static str, Part)>;
+type VariantFunction = fn(&ParsedInput, usize) -> Result<Answer, SolveError>;
+
+fn parse_erased<S: Solution>(input: &str, params: &Params) -> Result<ParsedInput, SolveError> {
+    Ok(ParsedInput(Box::new(S::parse_with(input, params)?)))
+}
+
+fn parsed<S: Solution>(parsed: &ParsedInput) -> &S::Parsed {
+    parsed
+        .0
+        .downcast_ref()
+        .expect("Input should have been parsed by the same day")
+}
+
+fn part1_erased<S: Solution>(input: &ParsedInput) -> Result<Answer, SolveError> {
+    S::part1(parsed::<S>(input))
+}
+
+fn part2_erased<S: Solution>(input: &ParsedInput) -> Result<Answer, SolveError> {
+    S::part2(parsed::<S>(input))
+}
+
+fn variants_erased<S: Solution>() -> Vec<(&'static str, Part)> {
+    S::variants()
+        .iter()
+        .map(|variant| (variant.name, variant.part))
+        .collect()
+}
+
+fn variant_erased<S: Solution>(input: &ParsedInput, idx: usize) -> Result<Answer, SolveError> {
+    (S::variants()[idx].solve)(parsed::<S>(input))
+}
+
+/// A [`Solution`] with its types erased, so days of a year can live in one list.
 pub struct AdventOfCodeDay<'a> {
+    pub year: u64,
+    pub day: u64,
     pub name: &'a str,
-    pub part1: SolveFunction,
-    pub part2: SolveFunction,
+    params: &'static [Param],
+    parse: Option<ParseFunction>,
+    part1: Option<PartFunction>,
+    part2: Option<PartFunction>,
+    variants: Option<(VariantsFunction, VariantFunction)>,
 }
 
-impl AdventOfCodeDay<'_> {
+impl<'a> AdventOfCodeDay<'a> {
+    #[must_use]
+    pub const fn new<S: Solution>(name: &'a str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            name,
+            params: S::PARAMS,
+            parse: Some(parse_erased::<S>),
+            part1: Some(part1_erased::<S>),
+            part2: Some(part2_erased::<S>),
+            variants: Some((variants_erased::<S>, variant_erased::<S>)),
+        }
+    }
+
+    /// For days where `S::part2` isn't solved yet.
+    #[must_use]
+    pub const fn part1_only<S: Solution>(name: &'a str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            name,
+            params: S::PARAMS,
+            parse: Some(parse_erased::<S>),
+            part1: Some(part1_erased::<S>),
+            part2: None,
+            variants: Some((variants_erased::<S>, variant_erased::<S>)),
+        }
+    }
+
+    /// For generated days where neither part of `S` is solved yet, so they show up as unsolved.
+    #[must_use]
+    pub const fn parse_only<S: Solution>(name: &'a str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            name,
+            params: S::PARAMS,
+            parse: Some(parse_erased::<S>),
+            part1: None,
+            part2: None,
+            variants: Some((variants_erased::<S>, variant_erased::<S>)),
+        }
+    }
+
+    #[must_use]
+    pub const fn unsolved(year: u64, day: u64, name: &'a str) -> Self {
+        Self {
+            year,
+            day,
+            name,
+            params: &[],
+            parse: None,
+            part1: None,
+            part2: None,
+            variants: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn progress(&self) -> DayProgress {
+        match (self.part1.is_some(), self.part2.is_some()) {
+            (true, true) => DayProgress::FullySolved,
+            (true, false) => DayProgress::PartlySolved,
+            _ => DayProgress::Unsolved,
+        }
+    }
+
+    #[must_use]
+    pub const fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => self.part1.is_some(),
+            Part::Two => self.part2.is_some(),
+        }
+    }
+
+    /// The parts that are solved, in order.
+    #[must_use]
+    pub fn parts(&self) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|part| self.has_part(*part))
+            .collect()
+    }
+
+    /// The parameters the day declares, see [`Solution::PARAMS`].
+    #[must_use]
+    pub const fn params(&self) -> &'static [Param] {
+        self.params
+    }
+
+    #[must_use]
+    pub fn default_params(&self) -> Params {
+        Params::defaults(self.params)
+    }
+
+    /// `None` if the day isn't solved at all. Panics while parsing are caught.
+    #[must_use]
+    pub fn parse(&self, input: &str) -> Option<Result<ParsedInput, SolveError>> {
+        self.parse_with(input, &self.default_params())
+    }
+
+    /// Like [`AdventOfCodeDay::parse`], with `params` overriding the defaults.
+    #[must_use]
+    pub fn parse_with(
+        &self,
+        input: &str,
+        params: &Params,
+    ) -> Option<Result<ParsedInput, SolveError>> {
+        let parse = self.parse?;
+
+        Some(catch_panic(|| parse(input, params)))
+    }
+
+    /// `None` if the part isn't solved. Panics while solving are caught, including
+    /// the one for an `input` parsed by another day.
+    #[must_use]
+    pub fn solve(&self, input: &ParsedInput, part: Part) -> Option<Result<Answer, SolveError>> {
+        let solve = match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }?;
+
+        Some(catch_panic(|| solve(input)))
+    }
+
+    /// Names of the variants of `part`, if it's solved.
+    #[must_use]
+    pub fn variants(&self, part: Part) -> Vec<&'static str> {
+        let Some((variants, _)) = self.variants.filter(|_| self.has_part(part)) else {
+            return vec![];
+        };
+
+        variants()
+            .into_iter()
+            .filter(|(_, variant_part)| *variant_part == part)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Like [`AdventOfCodeDay::solve`], with the variant of `part` called `name`.
+    /// `None` if there's no such variant.
+    #[must_use]
+    pub fn solve_variant(
+        &self,
+        input: &ParsedInput,
+        part: Part,
+        name: &str,
+    ) -> Option<Result<Answer, SolveError>> {
+        let (variants, solve) = self.variants.filter(|_| self.has_part(part))?;
+
+        let idx = variants()
+            .iter()
+            .position(|variant| *variant == (name, part))?;
+
+        Some(catch_panic(|| solve(input, idx)))
+    }
+
+    /// Parses `input` and solves `part` with it.
+    #[must_use]
+    pub fn run(&self, input: &str, part: Part) -> Option<Result<Answer, SolveError>> {
+        self.run_with(input, part, &self.default_params())
+    }
+
+    /// Like [`AdventOfCodeDay::run`], with `params` overriding the defaults.
+    #[must_use]
+    pub fn run_with(
+        &self,
+        input: &str,
+        part: Part,
+        params: &Params,
+    ) -> Option<Result<Answer, SolveError>> {
+        if !self.has_part(part) {
+            return None;
+        }
+
+        match self.parse_with(input, params)? {
+            Ok(parsed) => self.solve(&parsed, part),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Whether `answer` can be sent to Advent of Code. Answers found with overridden
+    /// `params` are for another puzzle than the one on the site.
     #[must_use]
+    pub fn is_submittable(&self, answer: &Answer, params: &Params) -> bool {
+        answer.is_submittable() && *params == self.default_params()
+    }
+}
+
+/// The solved days of a year, sorted by day. Days that aren't solved at all can be left out.
+pub struct AdventOfCodeYear<'a> {
+    pub year: u64,
+    pub days: Vec<AdventOfCodeDay<'a>>,
+}
+
+impl<'a> AdventOfCodeYear<'a> {
     /// # Panics
-    /// Advent of Code days are separated into two parts, with the second
-    /// one being unlocked only after you finish the first. It shouldn't
-    /// be possible for only Part 2 to be solved as it indicates that
-    /// part 1 has just not been added. This requirement can be relaxed
-    /// but it implies an error most of the time.
-    pub fn progress(&self) -> DayProgress {
-        match (self.part1, self.part2) {
-            (Some(_), Some(_)) => DayProgress::FullySolved,
-            (Some(_), None) => DayProgress::PartlySolved,
-            (None, None) => DayProgress::Unsolved,
-            _ => panic!("Didn't expect only Part 2 to be solved!"),
+    /// If a day belongs to another year, isn't on the calendar, is registered twice
+    /// or has a variant called [`DEFAULT_VARIANT`].
+    #[must_use]
+    pub fn new(year: u64, mut days: Vec<AdventOfCodeDay<'a>>) -> Self {
+        days.sort_by_key(|day| day.day);
+
+        for (idx, day) in days.iter().enumerate() {
+            assert_eq!(
+                day.year, year,
+                "Day {} ({}) was registered for {year}",
+                day.day, day.name
+            );
+            assert!(
+                (1..=days_in_event(year)).contains(&day.day),
+                "Day {} ({}) isn't on the calendar",
+                day.day,
+                day.name
+            );
+            assert!(
+                idx == 0 || days[idx - 1].day != day.day,
+                "Day {} of {year} is registered twice",
+                day.day
+            );
+            assert!(
+                !day.parts()
+                    .into_iter()
+                    .any(|part| day.variants(part).contains(&DEFAULT_VARIANT)),
+                "Day {} of {year} has a variant called {DEFAULT_VARIANT}",
+                day.day
+            );
         }
+
+        Self { year, days }
+    }
+
+    #[must_use]
+    pub fn day(&self, day: u64) -> Option<&AdventOfCodeDay<'a>> {
+        self.days.iter().find(|solution| solution.day == day)
     }
+
+    /// Every day of the event, with gaps where nothing is registered.
+    pub fn calendar(&self) -> impl Iterator<Item = (u64, Option<&AdventOfCodeDay<'a>>)> {
+        (1..=days_in_event(self.year)).map(|day| (day, self.day(day)))
+    }
+}
+
+/// Declares the day modules of a year and collects their `SOLUTION`s, so a day
+/// only has to be listed once. Each day places itself on the calendar.
+macro_rules! register_days {
+    ($($day:ident),* $(,)?) => {
+        $(mod $day;)*
+
+        pub fn get_days<'a>() -> Vec<$crate::solves::year::AdventOfCodeDay<'a>> {
+            vec![$($day::SOLUTION),*]
+        }
+    };
 }
 
+pub(crate) use register_days;
+
 #[cfg(test)]
 mod test {
-    use super::{AdventOfCodeDay, DayProgress, SolveFunction};
+    use super::{AdventOfCodeDay, AdventOfCodeYear, DayProgress, Part};
+    use crate::solves::{
+        params::Param,
+        solution::{Answer, Solution, SolveError, Variant, DEFAULT_VARIANT},
+    };
 
-    fn helper_solve(s: &str) -> String {
-        s.to_string()
-    }
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = Vec<String>;
 
-    fn new_day<'a>(part1: SolveFunction, part2: SolveFunction) -> AdventOfCodeDay<'a> {
-        AdventOfCodeDay {
-            name: "irrelevant",
-            part1,
-            part2,
+        const YEAR: u64 = 2019;
+        const DAY: u64 = 3;
+        const PARAMS: &'static [Param] = &[Param::number("skip", "Lines to leave out", 0)];
+
+        fn parse(input: &str) -> Result<Self::Parsed, SolveError> {
+            if input.is_empty() {
+                return Err(SolveError::invalid("no lines"));
+            }
+
+            Ok(input.lines().map(str::to_string).collect())
         }
-    }
 
-    #[test]
-    #[should_panic(expected = "Part 2")]
-    fn should_panic_on_only_part2_solved() {
-        let _ = new_day(None, Some(helper_solve)).progress();
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.len().into())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            let last = parsed.last().expect("Input should have lines");
+
+            Ok(last
+                .parse::<u64>()
+                .expect("Last line should be a number")
+                .into())
+        }
+
+        fn variants() -> Vec<Variant<Self>> {
+            vec![Variant {
+                name: "first",
+                part: Part::Two,
+                solve: |parsed| Ok(parsed[0].len().into()),
+            }]
+        }
     }
 
     #[test]
     fn should_return_correct_progress() {
         assert!(matches!(
-            new_day(None, None).progress(),
+            AdventOfCodeDay::unsolved(2019, 1, "irrelevant").progress(),
+            DayProgress::Unsolved
+        ));
+
+        assert!(matches!(
+            AdventOfCodeDay::parse_only::<Lines>("irrelevant").progress(),
             DayProgress::Unsolved
         ));
 
         assert!(matches!(
-            new_day(Some(helper_solve), None).progress(),
+            AdventOfCodeDay::part1_only::<Lines>("irrelevant").progress(),
             DayProgress::PartlySolved
         ));
 
         assert!(matches!(
-            new_day(Some(helper_solve), Some(helper_solve)).progress(),
+            AdventOfCodeDay::new::<Lines>("irrelevant").progress(),
             DayProgress::FullySolved
         ));
     }
-}
 
-pub struct AdventOfCodeYear<'a> {
-    pub year: u64,
-    pub days: Vec<AdventOfCodeDay<'a>>,
+    #[test]
+    fn should_share_parsed_input_between_parts() {
+        let day = AdventOfCodeDay::new::<Lines>("irrelevant");
+        let parsed = day.parse("a\n3").unwrap().unwrap();
+
+        assert_eq!(day.solve(&parsed, Part::One), Some(Ok(Answer::Number(2))));
+        assert_eq!(day.solve(&parsed, Part::Two), Some(Ok(Answer::Number(3))));
+
+        let partial = AdventOfCodeDay::part1_only::<Lines>("irrelevant");
+        assert_eq!(partial.run("a", Part::Two), None);
+        assert_eq!(partial.parts(), vec![Part::One]);
+    }
+
+    #[test]
+    fn should_report_errors_and_panics_instead_of_unwinding() {
+        let day = AdventOfCodeDay::new::<Lines>("irrelevant");
+
+        assert_eq!(
+            day.run("", Part::One),
+            Some(Err(SolveError::Invalid(String::from("no lines"))))
+        );
+
+        let Some(Err(SolveError::Panicked { message, location })) = day.run("a\nb", Part::Two)
+        else {
+            panic!("part 2 should have panicked");
+        };
+
+        assert!(message.starts_with("Last line should be a number"));
+        assert!(location.unwrap().starts_with("src/solves/year.rs:"));
+    }
+
+    #[test]
+    fn should_solve_variants_by_name() {
+        let day = AdventOfCodeDay::new::<Lines>("irrelevant");
+        let parsed = day.parse("abc\n3").unwrap().unwrap();
+
+        assert!(day.variants(Part::One).is_empty());
+        assert_eq!(day.variants(Part::Two), vec!["first"]);
+        assert_eq!(
+            day.solve_variant(&parsed, Part::Two, "first"),
+            Some(Ok(Answer::Number(3)))
+        );
+        assert_eq!(day.solve_variant(&parsed, Part::One, "first"), None);
+
+        let partial = AdventOfCodeDay::part1_only::<Lines>("irrelevant");
+        assert!(partial.variants(Part::Two).is_empty());
+    }
+
+    #[test]
+    fn should_only_submit_answers_for_default_params() {
+        let day = AdventOfCodeDay::new::<Lines>("irrelevant");
+        let answer = Answer::Number(2);
+
+        let mut params = day.default_params();
+        assert!(day.is_submittable(&answer, &params));
+        assert!(!day.is_submittable(&Answer::Grid(vec![]), &params));
+
+        params.set("skip", "1").unwrap();
+        assert!(!day.is_submittable(&answer, &params));
+
+        params.set("skip", "0").unwrap();
+        assert!(day.is_submittable(&answer, &params));
+    }
+
+    #[test]
+    fn should_place_days_on_the_calendar() {
+        let year = AdventOfCodeYear::new(
+            2019,
+            vec![
+                AdventOfCodeDay::new::<Lines>("three"),
+                AdventOfCodeDay::unsolved(2019, 1, "one"),
+            ],
+        );
+
+        assert_eq!(
+            year.days.iter().map(|day| day.day).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(year.day(3).map(|day| day.name), Some("three"));
+        assert!(year.day(2).is_none());
+
+        let calendar: Vec<_> = year.calendar().collect();
+        assert_eq!(calendar.len(), 25);
+        assert!(calendar[1].1.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 1 of 2019 is registered twice")]
+    fn should_panic_on_duplicate_days() {
+        let _unused_year = AdventOfCodeYear::new(
+            2019,
+            vec![
+                AdventOfCodeDay::unsolved(2019, 1, "one"),
+                AdventOfCodeDay::unsolved(2019, 1, "also one"),
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 4 of 2019 has a variant called default")]
+    fn should_panic_on_variants_named_like_the_part() {
+        struct Shadowing;
+
+        impl Solution for Shadowing {
+            type Parsed = ();
+
+            const YEAR: u64 = 2019;
+            const DAY: u64 = 4;
+
+            fn parse(_input: &str) -> Result<Self::Parsed, SolveError> {
+                Ok(())
+            }
+
+            fn part1(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+                Ok(Answer::Number(1))
+            }
+
+            fn part2(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+                Ok(Answer::Number(2))
+            }
+
+            fn variants() -> Vec<Variant<Self>> {
+                vec![Variant {
+                    name: DEFAULT_VARIANT,
+                    part: Part::One,
+                    solve: |()| Ok(Answer::Number(1)),
+                }]
+            }
+        }
+
+        let _unused_year =
+            AdventOfCodeYear::new(2019, vec![AdventOfCodeDay::new::<Shadowing>("four")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "was registered for 2020")]
+    fn should_panic_on_days_from_another_year() {
+        let _unused_year =
+            AdventOfCodeYear::new(2020, vec![AdventOfCodeDay::new::<Lines>("three")]);
+    }
 }
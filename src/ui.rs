@@ -1,45 +1,112 @@
-use std::time::Instant;
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    iter,
+    time::{Duration, Instant, SystemTime},
+};
 
 use colored::Colorize;
 
 use crate::{
     solves::{
-        get_data, get_years,
-        year::{AdventOfCodeDay, AdventOfCodeYear, DayProgress, SolveFunction},
+        bench::{BenchSettings, Stats},
+        benchmark_day,
+        cache::{self, CachedInput},
+        catalog::latest_day,
+        check_session,
+        custom::CustomInput,
+        data_dir::DataDir,
+        error::DataError,
+        get_custom_inputs, get_data, get_earned, get_event, get_examples, get_known_answers,
+        get_leaderboard, get_profile_data, get_profiles, get_puzzle, get_years,
+        known::Check,
+        leaderboard::{Member, LEADERBOARD_REFRESH},
+        leaderboard_id,
+        ledger::{Ledger, Outcome},
+        lock_in_answer, new_day,
+        params::Params,
+        puzzle::render,
+        refresh_puzzle, save_leaderboard_id,
+        solution::{Answer, SolveError, DEFAULT_VARIANT},
+        submit::{SubmitError, Verdict},
+        submit_answer, sync_earned,
+        unlock::{next_unlock, wait_for_unlock, SystemClock},
+        verify_day,
+        year::{AdventOfCodeDay, AdventOfCodeYear, DayProgress, Part},
     },
-    ui::utils::warn,
+    ui::utils::{get_stdin_line, get_stdin_number, warn},
 };
 
-use self::{menu::Menu, utils::format_result_runtime};
+use self::{
+    menu::Menu,
+    utils::{
+        format_countdown, format_duration, format_result_runtime, format_timestamp, new_menu,
+        DisplayFn, SharedContent,
+    },
+};
 
 pub mod banner;
 pub mod menu;
 pub mod utils;
 
 pub fn start_menu() {
+    integrity_menu();
+
     let years = get_years();
 
+    let find_year = |year: u64| years.iter().find(|solutions| solutions.year == year);
+
     let mut menu = Menu::new("");
 
     menu.add(-3, "Latest Year", || {
-        year_menu(&years[years.len() - 1]);
+        if let Some(year) = latest_day(&SystemClock, &years).and_then(|(year, _)| find_year(year)) {
+            year_menu(year);
+        }
     });
 
     menu.color(-3, colored::Color::Green);
 
     menu.add(-2, "Latest Day", || {
-        let latest_year = years.len() - 1;
-        let latest_day = &years[latest_year].days.len() - 1;
+        let latest =
+            latest_day(&SystemClock, &years).and_then(|(year, day)| Some((find_year(year)?, day)));
 
-        day_menu(
-            latest_day,
-            &years[latest_year].days[latest_day],
-            years[latest_year].year,
-        );
+        if let Some((year, day)) = latest {
+            open_day(year, day);
+        }
     });
 
     menu.color(-2, colored::Color::Green);
 
+    menu.add(-4, "Check Session", session_menu);
+    menu.color(-4, colored::Color::Yellow);
+
+    menu.add(-5, "Countdown", countdown_menu);
+    menu.color(-5, colored::Color::Yellow);
+
+    menu.add(-6, "Manage Cache", cache_menu);
+    menu.color(-6, colored::Color::Yellow);
+
+    menu.add(-7, "Verify All", || {
+        verify_menu("All Years", years.iter().flat_map(|year| &year.days));
+    });
+    menu.color(-7, colored::Color::Yellow);
+
+    menu.add(-8, "New Day", || {
+        new_menu();
+        println!("Type the year of the new day:");
+        let Some(year) = get_stdin_number().and_then(|year| u64::try_from(year).ok()) else {
+            return;
+        };
+
+        println!("Type the day:");
+        let Some(day) = get_stdin_number().and_then(|day| u64::try_from(day).ok()) else {
+            return;
+        };
+
+        new_day_menu(year, day);
+    });
+    menu.color(-8, colored::Color::Yellow);
+
     menu.add_back_option("Exit");
 
     years.iter().enumerate().for_each(|(idx, year)| {
@@ -57,12 +124,19 @@ pub fn start_menu() {
                     }
                 }
 
-                format!(
-                    "Year {} - {}{}",
-                    year.year,
+                let implemented = format!(
+                    "{}{}",
                     "*".repeat(full_solve_count).bright_yellow(),
                     "*".repeat(half_solve_count).bright_magenta()
-                )
+                );
+
+                DisplayFn(move || {
+                    format!(
+                        "Year {} - implemented {implemented} - earned {}",
+                        year.year,
+                        format!("{}*", get_earned(year.year).stars()).bright_cyan()
+                    )
+                })
             },
             || year_menu(year),
         );
@@ -71,122 +145,1276 @@ pub fn start_menu() {
     menu.display();
 }
 
+fn session_menu() {
+    let content = match check_session() {
+        Ok((user, source)) => format!(
+            "Logged in as {user} (session from {source}, {} profile)",
+            DataDir::current().profile_name()
+        )
+        .green()
+        .to_string(),
+        Err(e) => format!("{}\n{}", e.to_string().red(), e.next_step().yellow()),
+    };
+
+    let mut menu = Menu::new(content);
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn countdown_menu() {
+    let (year, day) = next_unlock(&SystemClock);
+
+    new_menu();
+    println!("Waiting for Day {day} of {year} to unlock...");
+
+    wait_for_unlock(&SystemClock, year, day, |remaining| {
+        print!("\r{}", format_countdown(remaining).bright_yellow());
+        let _unused_result = io::stdout().flush();
+    });
+
+    let data = match get_data(year, day) {
+        Ok(data) => data,
+        Err(e) => return data_error_menu("Couldn't load data for the unlocked day.", &e),
+    };
+
+    let mut menu = Menu::new(format!("--- Day {day} of {year} ---\n{data}"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn cache_listing(inputs: &[CachedInput], title: &str) -> String {
+    let mut content = vec![title.to_string()];
+
+    for input in inputs {
+        let fetched = input.meta.as_ref().map_or_else(
+            || String::from("fetched at an unknown time"),
+            |meta| {
+                format!(
+                    "fetched {} with session {}",
+                    format_timestamp(meta.fetched),
+                    meta.session.as_deref().unwrap_or("-")
+                )
+            },
+        );
+
+        let line = format!(
+            "{} Day {:>2} - {:>6} bytes - {:016x} - {fetched}",
+            input.year, input.day, input.size, input.hash
+        );
+
+        content.push(match &input.problem {
+            Some(problem) => format!("{line} - {}", format!("suspicious, {problem}").red()),
+            None => line,
+        });
+    }
+
+    if content.len() == 1 {
+        content.push(String::from("Nothing is cached."));
+    }
+
+    content.join("\n")
+}
+
+/// Warns about cached inputs that would only make solutions fail, before anything runs.
+fn integrity_menu() {
+    let data = DataDir::current();
+
+    let Ok(flagged) = cache::check_integrity(data) else {
+        return;
+    };
+
+    if flagged.is_empty() {
+        return;
+    }
+
+    let content = RefCell::new(cache_listing(
+        &flagged,
+        &"--- Some cached inputs don't look like inputs ---"
+            .red()
+            .to_string(),
+    ));
+    let mut menu = Menu::new(SharedContent(&content));
+
+    menu.add_conditional(
+        1,
+        "Quarantine All",
+        || {
+            flagged
+                .iter()
+                .any(|input| data.input(input.year, input.day).exists())
+        },
+        || {
+            let mut moved = vec![String::from("--- Quarantined ---")];
+
+            for input in &flagged {
+                match cache::quarantine_cached(data, input.year, input.day, SystemTime::now()) {
+                    Ok(path) => moved.push(format!(
+                        "{} Day {} -> {}",
+                        input.year,
+                        input.day,
+                        path.display()
+                    )),
+                    Err(e) => moved.push(
+                        format!("{} Day {} - {e}", input.year, input.day)
+                            .red()
+                            .to_string(),
+                    ),
+                }
+            }
+
+            *content.borrow_mut() = moved.join("\n");
+        },
+    );
+    menu.color(1, colored::Color::Yellow);
+
+    menu.add_back_option("Continue");
+    menu.display();
+}
+
+fn cache_menu() {
+    let data = DataDir::current();
+
+    let inputs = match cache::list(data) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            let e = DataError::cache_io(data.cache(), e);
+            return data_error_menu("Couldn't read the cache.", &e);
+        }
+    };
+
+    let title = format!(
+        "--- Cache - {} ({} profile) ---",
+        data.root().display(),
+        data.profile_name()
+    );
+    let content = RefCell::new(cache_listing(&inputs, &title));
+    let mut menu = Menu::new(SharedContent(&content));
+
+    let mut years: Vec<u64> = inputs.iter().map(|input| input.year).collect();
+    years.dedup();
+
+    for (option, year) in (1..).zip(years) {
+        let title = &title;
+        let content = &content;
+
+        menu.add_conditional(
+            option,
+            format!("Year {year}"),
+            move || cache::has_year(data, year),
+            move || {
+                cache_year_menu(year);
+
+                if let Ok(inputs) = cache::list(data) {
+                    *content.borrow_mut() = cache_listing(&inputs, title);
+                }
+            },
+        );
+    }
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn cache_year_menu(year: u64) {
+    let data = DataDir::current();
+
+    let list_year = || -> Vec<CachedInput> {
+        cache::list(data)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|input| input.year == year)
+            .collect()
+    };
+
+    let title = format!("--- Cache - {year} ---");
+    let inputs = list_year();
+    let content = RefCell::new(cache_listing(&inputs, &title));
+    let refresh = || *content.borrow_mut() = cache_listing(&list_year(), &title);
+
+    let mut menu = Menu::new(SharedContent(&content));
+
+    menu.add_conditional(
+        -1,
+        "Delete Year",
+        || cache::has_year(data, year),
+        || match cache::delete_year(data, year) {
+            Ok(()) => refresh(),
+            Err(e) => warn(&e.to_string()),
+        },
+    );
+    menu.color(-1, colored::Color::Red);
+
+    for input in inputs {
+        let day = input.day;
+        let refresh = &refresh;
+
+        let Ok(option) = i64::try_from(day) else {
+            continue;
+        };
+
+        if input.problem.is_some() {
+            menu.add_conditional(
+                -option - 1,
+                format!("Quarantine Day {day}"),
+                move || data.input(year, day).exists(),
+                move || match cache::quarantine_cached(data, year, day, SystemTime::now()) {
+                    Ok(_) => refresh(),
+                    Err(e) => warn(&e.to_string()),
+                },
+            );
+            menu.color(-option - 1, colored::Color::Yellow);
+        }
+
+        menu.add_conditional(
+            option,
+            format!("Delete Day {day}"),
+            move || data.input(year, day).exists(),
+            move || match cache::delete_day(data, year, day) {
+                Ok(()) => refresh(),
+                Err(e) => warn(&e.to_string()),
+            },
+        );
+    }
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn new_day_menu(year: u64, day: u64) {
+    let (written, problems) = match new_day(year, day) {
+        Ok(scaffolded) => scaffolded,
+        Err(e) => return warn(&e.to_string()),
+    };
+
+    let mut content = vec![format!("--- New Day - Day {day} of {year} ---")];
+
+    content.extend(
+        written
+            .iter()
+            .map(|path| format!("Wrote {}", path.display()).green().to_string()),
+    );
+
+    for e in &problems {
+        content.push(e.to_string().red().to_string());
+        content.push(e.next_step().yellow().to_string());
+    }
+
+    content.push(String::from(
+        "Rebuild to pick up the new day, its parts are todo!() until solved.",
+    ));
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
 fn year_menu(year: &AdventOfCodeYear) {
+    let earned = RefCell::new(get_earned(year.year));
+
     let mut menu = Menu::new(format!("--- {} ---", year.year));
 
-    menu.add(-3, "Benchmark", || warn("Not implemented!"));
+    menu.add(-4, "Sync Stars", || match sync_earned(year.year) {
+        Ok(synced) => *earned.borrow_mut() = synced,
+        Err(e) => data_error_menu("Couldn't sync stars for current year.", &e),
+    });
+    menu.color(-4, colored::Color::Yellow);
+
+    menu.add(-5, "Leaderboard", || leaderboard_menu(year.year));
+    menu.color(-5, colored::Color::Yellow);
+
+    menu.add(-6, "Verify Year", || {
+        verify_menu(&year.year.to_string(), &year.days);
+    });
+    menu.color(-6, colored::Color::Yellow);
+
+    menu.add(-3, "Benchmark", || benchmark_menu(year));
     menu.color(-3, colored::Color::Yellow);
 
-    menu.add(-2, "Latest Day", || warn(""));
+    menu.add(-2, "Latest Day", || {
+        if let Some((_, day)) = latest_day(&SystemClock, std::slice::from_ref(year)) {
+            open_day(year, day);
+        }
+    });
     menu.color(-2, colored::Color::Green);
 
     menu.add_back_option("Go Back");
 
-    year.days.iter().enumerate().for_each(|(idx, day)| {
+    for event_day in get_event(year) {
+        let earned = &earned;
+        let day_number = event_day.day;
+        let Ok(option) = i64::try_from(day_number) else {
+            continue;
+        };
+
         menu.add(
-            (idx + 1) as i64,
-            format!(
-                "Day [{}] - {} - {}",
-                idx + 1,
-                day.name,
-                match day.progress() {
-                    DayProgress::FullySolved => "**".bright_yellow(),
-                    DayProgress::PartlySolved => "*".bright_yellow(),
-                    DayProgress::Unsolved => "".bright_yellow(),
+            option,
+            DisplayFn(move || {
+                let earned = "*".repeat(earned.borrow().day(day_number).stars.into());
+                let title = event_day.title.as_deref().unwrap_or("???");
+
+                match year.day(day_number) {
+                    Some(day) => format!(
+                        "Day [{day_number}] - {title} - implemented {} - earned {}",
+                        match day.progress() {
+                            DayProgress::FullySolved => "**".bright_yellow(),
+                            DayProgress::PartlySolved => "*".bright_yellow(),
+                            DayProgress::Unsolved => "".bright_yellow(),
+                        },
+                        earned.bright_cyan()
+                    ),
+                    None if SystemTime::now() < event_day.unlocks => format!(
+                        "Day [{day_number}] - unlocks {}",
+                        format_timestamp(event_day.unlocks)
+                    )
+                    .dimmed()
+                    .to_string(),
+                    None => format!(
+                        "Day [{day_number}] - {title} - not implemented - earned {}",
+                        earned.bright_cyan()
+                    )
+                    .dimmed()
+                    .to_string(),
                 }
-            ),
-            move || day_menu(idx, day, year.year),
+            }),
+            move || open_day(year, day_number),
         );
-    });
+    }
 
     menu.display();
 }
 
-fn day_menu(idx: usize, day: &AdventOfCodeDay, year: u64) {
+/// Opens the day's solution, or an empty slot for days without one.
+fn open_day(year: &AdventOfCodeYear, day_number: u64) {
+    if let Some(day) = year.day(day_number) {
+        return day_menu(day);
+    }
+
+    let title = get_event(year)
+        .into_iter()
+        .find(|event_day| event_day.day == day_number)
+        .and_then(|event_day| event_day.title);
+
+    day_menu(&AdventOfCodeDay::unsolved(
+        year.year,
+        day_number,
+        title.as_deref().unwrap_or("???"),
+    ));
+}
+
+fn leaderboard_menu(year: u64) {
+    let id = if let Some(id) = leaderboard_id() {
+        id
+    } else {
+        new_menu();
+        println!("Type the id of the private leaderboard, the number at the end of its URL:");
+
+        let Some(id) = get_stdin_number().and_then(|id| u64::try_from(id).ok()) else {
+            return;
+        };
+
+        if let Err(e) = save_leaderboard_id(id) {
+            warn(&e.to_string());
+        }
+
+        id
+    };
+
+    let (leaderboard, age) = match get_leaderboard(year, id) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => return data_error_menu("Couldn't load the private leaderboard.", &e),
+    };
+
+    let mut content = vec![format!(
+        "--- Private Leaderboard {id} - {year} ---\nUpdated {} ago, next update in {}",
+        format_countdown(age),
+        format_countdown(LEADERBOARD_REFRESH.saturating_sub(age))
+    )];
+
+    let standings = leaderboard.standings();
+
+    for (rank, member) in standings.iter().enumerate() {
+        content.push(format!(
+            "{:>3}) {:>5} {:>3}* {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    for (rank, member) in (1..).zip(standings) {
+        menu.add(rank, member.display_name(), move || {
+            member_menu(member, year);
+        });
+    }
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn member_menu(member: &Member, year: u64) {
+    let mut content = vec![format!(
+        "--- {} - {} ---\nDay   Part 1     Part 2     Delta",
+        member.display_name(),
+        year
+    )];
+
+    let format_time =
+        |time: Option<Duration>| time.map_or_else(|| String::from("-"), format_countdown);
+
+    for day in member.days() {
+        content.push(format!(
+            "{day:>3}   {:<10} {:<10} {}",
+            format_time(member.star_delay(year, day, Part::One)),
+            format_time(member.star_delay(year, day, Part::Two)),
+            format_time(member.delta(day)).bright_yellow()
+        ));
+    }
+
+    if content.len() == 1 {
+        content.push(String::from("No stars yet."));
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn day_menu(day: &AdventOfCodeDay) {
     let progress = day.progress();
 
     let part2_solved = matches!(progress, DayProgress::FullySolved);
     let part1_solved = matches!(progress, DayProgress::PartlySolved) || part2_solved;
+    let has_variants = day
+        .parts()
+        .into_iter()
+        .any(|part| !day.variants(part).is_empty());
+    let params = RefCell::new(day.default_params());
+
+    let year = day.year;
+    let day_number = day.day;
 
-    let mut menu = Menu::new(format!("--- Day {} - {} ---", idx + 1, day.name));
+    let mut menu = Menu::new(format!("--- Day {day_number} - {} ---", day.name));
 
     menu.add_conditional(
         -1,
         "Solve",
         || part1_solved,
-        || {
-            let part1_start = Instant::now();
-            let mut results = run_solve(day.part1, year, idx as u64);
-            let part1_duration = part1_start.elapsed();
+        || run_parts(day, &day.parts(), &params.borrow(), year, day_number),
+    );
+    menu.color(-1, colored::Color::Green);
 
-            results = format_result_runtime(&results, part1_duration);
+    menu.add_conditional(
+        1,
+        "Part 1",
+        || part1_solved,
+        || run_parts(day, &[Part::One], &params.borrow(), year, day_number),
+    );
 
-            if part2_solved {
-                let part2_start = Instant::now();
-                let part2_result = run_solve(day.part2, year, idx as u64);
-                let part2_duration = part2_start.elapsed();
+    menu.add_conditional(
+        2,
+        "Part 2",
+        || part2_solved,
+        || run_parts(day, &[Part::Two], &params.borrow(), year, day_number),
+    );
 
-                results = format!(
-                    "{results}\n{}",
-                    format_result_runtime(&part2_result, part2_duration)
-                );
-            }
+    menu.add(-2, "Answer Ledger", || ledger_menu(year, day_number));
+    menu.color(-2, colored::Color::Yellow);
 
-            let mut part_menu = Menu::new(results);
+    menu.add(-3, "Read Puzzle", || puzzle_menu(year, day_number));
+    menu.color(-3, colored::Color::Yellow);
 
-            part_menu.add_back_option("Go Back");
-            part_menu.display();
-        },
+    menu.add_conditional(
+        -4,
+        "Run Examples",
+        || part1_solved,
+        || examples_menu(day, &params.borrow(), year, day_number),
     );
-    menu.color(-1, colored::Color::Green);
+    menu.color(-4, colored::Color::Yellow);
 
     menu.add_conditional(
-        1,
-        "Part 1",
+        -5,
+        "Run Across Profiles",
         || part1_solved,
-        || {
-            let start = Instant::now();
-            let result = run_solve(day.part1, year, idx as u64);
-            let duration = start.elapsed();
+        || profiles_menu(day, &params.borrow(), year, day_number),
+    );
+    menu.color(-5, colored::Color::Yellow);
 
-            let mut part_menu = Menu::new(format_result_runtime(&result, duration));
+    menu.add_conditional(
+        -6,
+        "Inputs",
+        || part1_solved,
+        || inputs_menu(day, &params.borrow(), year, day_number),
+    );
+    menu.color(-6, colored::Color::Yellow);
 
-            part_menu.add_back_option("Go Back");
-            part_menu.display();
-        },
+    menu.add_conditional(
+        -7,
+        "Verify",
+        || part1_solved,
+        || verify_menu(&format!("Day {day_number}"), [day]),
     );
+    menu.color(-7, colored::Color::Yellow);
 
     menu.add_conditional(
-        2,
-        "Part 2",
-        || part2_solved,
-        || {
-            let start = Instant::now();
-            let result = run_solve(day.part2, year, idx as u64);
-            let duration = start.elapsed();
+        -8,
+        "Scaffold and Fetch",
+        || !part1_solved,
+        || new_day_menu(year, day_number),
+    );
+    menu.color(-8, colored::Color::Green);
 
-            let mut part_menu = Menu::new(format_result_runtime(&result, duration));
+    menu.add_conditional(
+        -9,
+        "Compare Variants",
+        || has_variants,
+        || variants_menu(day, &params.borrow(), year, day_number),
+    );
+    menu.color(-9, colored::Color::Yellow);
 
-            part_menu.add_back_option("Go Back");
-            part_menu.display();
-        },
+    menu.add_conditional(
+        -10,
+        DisplayFn(|| {
+            let overridden: Vec<String> = params
+                .borrow()
+                .iter()
+                .zip(day.params())
+                .filter(|((_, value), param)| **value != param.default)
+                .map(|((name, value), _)| format!("{name}={value}"))
+                .collect();
+
+            if overridden.is_empty() {
+                String::from("Parameters")
+            } else {
+                format!("Parameters ({})", overridden.join(", "))
+            }
+        }),
+        || !day.params().is_empty(),
+        || prompt_params(day, &mut params.borrow_mut()),
     );
+    menu.color(-10, colored::Color::Yellow);
 
     menu.add_back_option("Go Back");
 
     menu.display();
 }
 
-fn run_solve(solve_function: SolveFunction, year: u64, day: u64) -> String {
-    if let Ok(data) = get_data(year, day + 1) {
-        let result = solve_function
-            .expect("run_solve is only called when we know solve_function is Ok()")(
-            &data
+/// Asks for a value of every parameter of the day, keeping the current one on an empty line.
+fn prompt_params(day: &AdventOfCodeDay, params: &mut Params) {
+    new_menu();
+
+    for param in day.params() {
+        let current = params.get(param.name).unwrap_or(&param.default);
+
+        println!(
+            "{} - {} [{current}], default {}:",
+            param.name.yellow(),
+            param.description,
+            param.default
         );
 
-        format!("Result: {result}")
-    } else {
-        warn("Couldn't load data for current day.");
-        String::from("")
+        let value = get_stdin_line();
+
+        if value.is_empty() {
+            continue;
+        }
+
+        if let Err(e) = params.set(param.name, &value) {
+            warn(&e.to_string());
+        }
+    }
+}
+
+fn profiles_menu(day: &AdventOfCodeDay, params: &Params, year: u64, day_number: u64) {
+    let profiles = match get_profiles() {
+        Ok(profiles) => profiles,
+        Err(e) => return data_error_menu("Couldn't list profiles.", &e),
+    };
+
+    let mut content = vec![format!("--- Profiles - Day {day_number} ---")];
+
+    for profile in &profiles {
+        let name = profile.profile_name();
+
+        let data = match get_profile_data(profile, year, day_number) {
+            Ok(data) => data,
+            Err(e) => {
+                content.push(format!("{name:<16} {}", e.to_string().red()));
+                continue;
+            }
+        };
+
+        let mut columns = vec![format!("{name:<16}")];
+
+        let parsed = match day.parse_with(&data, params) {
+            Some(Ok(parsed)) => parsed,
+            Some(Err(e)) => {
+                content.push(format!("{name:<16} {}", e.to_string().red()));
+                continue;
+            }
+            None => continue,
+        };
+
+        for part in day.parts() {
+            let start = Instant::now();
+
+            let result = match day.solve(&parsed, part) {
+                Some(Ok(result)) => result.to_string(),
+                Some(Err(e)) => e.to_string().red().to_string(),
+                None => continue,
+            };
+
+            columns.push(format!(
+                "{:<20} {:>6}ms",
+                result.trim(),
+                start.elapsed().as_millis()
+            ));
+        }
+
+        content.push(columns.join(" | "));
+    }
+
+    if profiles.len() == 1 {
+        content.push(
+            "Add profiles by putting a session key in profiles/<name>/session.txt in the data directory."
+                .yellow()
+                .to_string(),
+        );
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// Runs every variant of each part on the same input, next to the main solution.
+fn variants_menu(day: &AdventOfCodeDay, params: &Params, year: u64, day_number: u64) {
+    let data = match get_data(year, day_number) {
+        Ok(data) => data,
+        Err(e) => return data_error_menu("Couldn't load data for current day.", &e),
+    };
+
+    let parsed = match day.parse_with(&data, params) {
+        Some(Ok(parsed)) => parsed,
+        Some(Err(e)) => return solve_error_menu("Couldn't parse the input for current day.", &e),
+        None => return,
+    };
+
+    let mut content = vec![format!("--- Variants - Day {day_number} ---")];
+
+    for part in day.parts() {
+        let names = day.variants(part);
+
+        if names.is_empty() {
+            continue;
+        }
+
+        let mut runs = vec![];
+
+        for variant in iter::once(None).chain(names.into_iter().map(Some)) {
+            let start = Instant::now();
+
+            let result = variant.map_or_else(
+                || day.solve(&parsed, part),
+                |name| day.solve_variant(&parsed, part, name),
+            );
+
+            if let Some(result) = result {
+                runs.push((variant.unwrap_or(DEFAULT_VARIANT), result, start.elapsed()));
+            }
+        }
+
+        let expected = runs.first().and_then(|(_, result, _)| result.as_ref().ok());
+        let fastest = runs
+            .iter()
+            .map(|(_, _, duration)| *duration)
+            .min()
+            .unwrap_or_default()
+            .max(Duration::from_micros(1));
+
+        content.push(format!("{part}:"));
+
+        let mut agree = expected.is_some();
+
+        for (name, result, duration) in &runs {
+            let answer = match result {
+                Ok(answer) if Some(answer) == expected => answer.to_string().green(),
+                Ok(answer) => {
+                    agree = false;
+                    answer.to_string().red()
+                }
+                Err(e) => {
+                    agree = false;
+                    e.to_string().red()
+                }
+            };
+
+            content.push(format!(
+                "  {name:<20} {:<20} {:>6}ms {:>8.2}x",
+                answer.trim(),
+                duration.as_millis(),
+                duration.as_secs_f64() / fastest.as_secs_f64()
+            ));
+        }
+
+        content.push(if agree {
+            "  All variants agree.".green().to_string()
+        } else {
+            "  Variants disagree!".red().to_string()
+        });
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn examples_menu(day: &AdventOfCodeDay, params: &Params, year: u64, day_number: u64) {
+    let examples = match get_examples(year, day_number) {
+        Ok(examples) => examples,
+        Err(e) => return data_error_menu("Couldn't load examples for current day.", &e),
+    };
+
+    let mut content = vec![format!("--- Examples - Day {day_number} ---")];
+
+    for example in examples.iter().filter(|example| day.has_part(example.part)) {
+        let mut params = params.clone();
+
+        if let Err(e) = params.apply(&example.params) {
+            content.push(format!("{} - ERROR - {e}", example.part).red().to_string());
+            continue;
+        }
+
+        content.push(check_solve(
+            day,
+            example.part,
+            &example.input,
+            &params,
+            example.answer.as_deref(),
+        ));
+    }
+
+    if content.len() == 1 {
+        content.push(String::from("No examples found for current day."));
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// Runs a part on `input`, reporting whether it matched `expected`.
+fn check_solve(
+    day: &AdventOfCodeDay,
+    part: Part,
+    input: &str,
+    params: &Params,
+    expected: Option<&str>,
+) -> String {
+    let start = Instant::now();
+    let result = match day.run_with(input, part, params) {
+        Some(Ok(result)) => result,
+        Some(Err(e)) => return format!("{part} - ERROR - {e}").red().to_string(),
+        None => return format!("{part} - NOT SOLVED").yellow().to_string(),
+    };
+    let duration = start.elapsed();
+
+    let line = match expected {
+        Some(answer) if result == answer.trim() => format!("{part} - PASS - {result}").green(),
+        Some(answer) => format!("{part} - FAIL - expected {answer}, got {result}").red(),
+        None => format!("{part} - NO EXPECTED ANSWER - got {result}").yellow(),
+    };
+
+    format_result_runtime(&line.to_string(), duration)
+}
+
+fn inputs_menu(day: &AdventOfCodeDay, params: &Params, year: u64, day_number: u64) {
+    let custom_inputs = match get_custom_inputs(year, day_number) {
+        Ok(inputs) => inputs,
+        Err(e) => return data_error_menu("Couldn't load custom inputs for current day.", &e),
+    };
+
+    let parts = day.parts();
+
+    let mut menu = Menu::new(format!("--- Inputs - Day {day_number} ---"));
+
+    menu.add(1, "Official Input", || {
+        run_parts(day, &parts, params, year, day_number);
+    });
+
+    for (option, custom_input) in (2..).zip(&custom_inputs) {
+        let parts = &parts;
+
+        menu.add(
+            option,
+            format!("Custom Input - {}", custom_input.name),
+            move || custom_results_menu(day, std::slice::from_ref(custom_input), parts, params),
+        );
+    }
+
+    if !custom_inputs.is_empty() {
+        menu.add(-1, "Run All Custom Inputs", || {
+            custom_results_menu(day, &custom_inputs, &parts, params);
+        });
+        menu.color(-1, colored::Color::Green);
+    }
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn custom_results_menu(
+    day: &AdventOfCodeDay,
+    custom_inputs: &[CustomInput],
+    parts: &[Part],
+    params: &Params,
+) {
+    let mut content = vec![];
+
+    for custom_input in custom_inputs {
+        content.push(format!("--- {} ---", custom_input.name));
+
+        for part in parts {
+            content.push(check_solve(
+                day,
+                *part,
+                &custom_input.input,
+                params,
+                custom_input.answer(*part),
+            ));
+        }
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn puzzle_menu(year: u64, day: u64) {
+    let page = match get_puzzle(year, day) {
+        Ok(page) => page,
+        Err(e) => return data_error_menu("Couldn't load puzzle for current day.", &e),
+    };
+
+    let content = RefCell::new(render(&page));
+    let mut menu = Menu::new(SharedContent(&content));
+
+    menu.add(1, "Refresh Puzzle", || match refresh_puzzle(year, day) {
+        Ok(page) => *content.borrow_mut() = render(&page),
+        Err(e) => data_error_menu("Couldn't refresh puzzle for current day.", &e),
+    });
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn ledger_menu(year: u64, day: u64) {
+    let data = DataDir::current();
+
+    let ledger = match Ledger::load(data, year, day) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            let e = DataError::cache_io(data.ledger(year, day), e);
+            return data_error_menu("Couldn't read the answer ledger for current day.", &e);
+        }
+    };
+
+    let mut content = vec![format!("--- Answer Ledger - Day {day} ---")];
+
+    for part in [Part::One, Part::Two] {
+        let entries: Vec<_> = ledger
+            .entries()
+            .iter()
+            .filter(|entry| entry.part == part)
+            .collect();
+
+        if entries.is_empty() {
+            continue;
+        }
+
+        content.push(format!("{part}:"));
+
+        for entry in entries {
+            let line = format!("  {} - {}", entry.answer, entry.outcome);
+
+            content.push(match entry.outcome {
+                Outcome::Correct => line.green().to_string(),
+                _ => line.red().to_string(),
+            });
+        }
+
+        match ledger.bounds(part) {
+            (None, None) => (),
+            (low, high) => content.push(format!(
+                "  Answer must be between {} and {}",
+                low.map_or_else(|| String::from("-inf"), |n| n.to_string()),
+                high.map_or_else(|| String::from("inf"), |n| n.to_string())
+            )),
+        }
+    }
+
+    if content.len() == 1 {
+        content.push(String::from("Nothing has been submitted yet."));
+    }
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// Results found with overridden `params` are shown as such and can't be submitted.
+fn results_menu(
+    parse_duration: Duration,
+    results: &[(Part, Result<Answer, SolveError>, Duration)],
+    day: &AdventOfCodeDay,
+    params: &Params,
+) {
+    let (year, day_number) = (day.year, day.day);
+    let custom = *params != day.default_params();
+    let earned = get_earned(year).day(day_number);
+
+    let render = || {
+        let known = get_known_answers(year, day_number);
+        let mut content = vec![format_result_runtime("Parsed input", parse_duration)];
+
+        content.extend(results.iter().map(|(part, result, duration)| {
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    return format_result_runtime(&e.to_string().red().to_string(), *duration)
+                }
+            };
+
+            if custom {
+                return format_result_runtime(
+                    &format!("Result: {result} {}", "(custom params)".yellow()),
+                    *duration,
+                );
+            }
+
+            let line = format_result_runtime(&format!("Result: {result}"), *duration);
+
+            let expected = known.get(*part).or_else(|| {
+                earned
+                    .answers
+                    .get(usize::from(part.number() - 1))
+                    .map(String::as_str)
+            });
+
+            match expected {
+                Some(answer) if *result == answer.trim() => {
+                    format!("{line} - {}", "matches the known answer".green())
+                }
+                Some(answer) => {
+                    format!("{line} - {}", format!("known answer is {answer}").red())
+                }
+                None => line,
+            }
+        }));
+
+        content.join("\n")
+    };
+
+    let content = RefCell::new(render());
+    let mut menu = Menu::new(SharedContent(&content));
+
+    for (part, result, _) in results {
+        let Ok(result) = result else {
+            continue;
+        };
+
+        if !day.is_submittable(result, params) {
+            continue;
+        }
+
+        let render = &render;
+        let content = &content;
+
+        menu.add(
+            i64::from(part.number()),
+            format!("Submit {part}"),
+            move || {
+                submit_menu(year, day_number, *part, &result.to_string());
+                *content.borrow_mut() = render();
+            },
+        );
+
+        menu.add(
+            -i64::from(part.number()),
+            format!("Lock In {part}"),
+            move || match lock_in_answer(year, day_number, *part, &result.to_string()) {
+                Ok(()) => *content.borrow_mut() = render(),
+                Err(e) => data_error_menu("Couldn't lock in the answer.", &e),
+            },
+        );
+        menu.color(-i64::from(part.number()), colored::Color::Yellow);
+    }
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// One row of the benchmark table, colored by how long the median took.
+fn benchmark_row(day: &str, step: &str, stats: &Stats) -> String {
+    let columns = [stats.min, stats.median, stats.mean, stats.stddev]
+        .map(|duration| format!("{:>10}", format_duration(duration)))
+        .join(" ");
+
+    let row = format!("{day:<8} {step:<8} {columns}");
+
+    match stats.median.as_millis() {
+        0 => row.green().to_string(),
+        1..=99 => row.yellow().to_string(),
+        _ => row.red().to_string(),
+    }
+}
+
+fn benchmark_menu(year: &AdventOfCodeYear) {
+    let mut settings = BenchSettings::default();
+
+    new_menu();
+    println!(
+        "How many times should each part run? [{}]:",
+        settings.iterations
+    );
+
+    let iterations = get_stdin_line();
+    if !iterations.is_empty() {
+        match iterations.parse() {
+            Ok(iterations) if iterations > 0 => settings.iterations = iterations,
+            _ => return warn("Please type a positive number!"),
+        }
+    }
+
+    let header = format!(
+        "{:<8} {:<8} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Min", "Median", "Mean", "Stddev"
+    );
+    let mut lines = vec![header.bold().to_string()];
+
+    let mut load = Duration::ZERO;
+    let mut parses = vec![];
+    let mut solves = vec![];
+
+    for day in year.days.iter().filter(|day| !day.parts().is_empty()) {
+        let name = format!("Day {:>2}", day.day);
+        println!("Benchmarking {name} - {}...", day.name);
+
+        let benchmark = match benchmark_day(day, settings) {
+            Ok(Some(benchmark)) => benchmark,
+            Ok(None) => {
+                lines.push(format!("{name:<8} input isn't cached").dimmed().to_string());
+                continue;
+            }
+            Err(e) => {
+                lines.push(format!("{name:<8} {e}").red().to_string());
+                continue;
+            }
+        };
+
+        load += benchmark.load;
+        lines.push(
+            format!(
+                "{name:<8} {:<8} {:>10}",
+                "Load",
+                format_duration(benchmark.load)
+            )
+            .dimmed()
+            .to_string(),
+        );
+
+        match benchmark.parse {
+            Ok(stats) => {
+                lines.push(benchmark_row(&name, "Parse", &stats));
+                parses.push(stats);
+            }
+            Err(e) => lines.push(format!("{name:<8} {:<8} {e}", "Parse").red().to_string()),
+        }
+
+        for (part, stats) in benchmark.parts {
+            let step = format!("Part {}", part.number());
+
+            match stats {
+                Ok(stats) => {
+                    lines.push(benchmark_row(&name, &step, &stats));
+                    solves.push(stats);
+                }
+                Err(e) => lines.push(format!("{name:<8} {step:<8} {e}").red().to_string()),
+            }
+        }
+    }
+
+    let parse = Stats::total(&parses);
+    let solve = Stats::total(&solves);
+
+    lines.push(String::new());
+    lines.push(
+        format!("{:<8} {:<8} {:>10}", "Total", "Load", format_duration(load))
+            .dimmed()
+            .to_string(),
+    );
+    lines.push(benchmark_row("Total", "Parse", &parse));
+    lines.push(benchmark_row("Total", "Solve", &solve));
+    lines.push(
+        benchmark_row("Total", "All", &Stats::total([&parse, &solve]))
+            .bold()
+            .to_string(),
+    );
+
+    let mut content = vec![format!(
+        "--- Benchmark - {} - {} runs after {} warm-up ---",
+        year.year, settings.iterations, settings.warmup
+    )];
+    content.extend(lines);
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// Reruns `days` on their cached inputs, flagging every answer that changed.
+fn verify_menu<'a>(title: &str, days: impl IntoIterator<Item = &'a AdventOfCodeDay<'a>>) {
+    let mut lines = vec![];
+    let mut matched = 0;
+    let mut regressed = 0;
+
+    for day in days {
+        let name = format!("{} Day {:>2}", day.year, day.day);
+
+        let checks = match verify_day(day) {
+            Ok(Some(checks)) => checks,
+            Ok(None) => {
+                lines.push(format!("{name} - input isn't cached").dimmed().to_string());
+                continue;
+            }
+            Err(e) => {
+                lines.push(format!("{name} - {e}").red().to_string());
+                regressed += 1;
+                continue;
+            }
+        };
+
+        for (part, check) in checks {
+            if check.is_regression() {
+                regressed += 1;
+            }
+
+            lines.push(match check {
+                Check::Match(answer) => {
+                    matched += 1;
+                    format!("{name} - {part} - OK - {answer}")
+                        .green()
+                        .to_string()
+                }
+                Check::Mismatch { expected, got } => {
+                    format!("{name} - {part} - MISMATCH - expected {expected}, got {got}")
+                        .red()
+                        .to_string()
+                }
+                Check::Failed(e) => format!("{name} - {part} - ERROR - {e}").red().to_string(),
+                Check::Unknown => format!("{name} - {part} - no known answer")
+                    .dimmed()
+                    .to_string(),
+            });
+        }
     }
+
+    let summary = format!("{matched} matched, {regressed} regressed");
+
+    let mut content = vec![
+        format!("--- Verify - {title} ---"),
+        if regressed == 0 {
+            summary.green().to_string()
+        } else {
+            summary.red().to_string()
+        },
+    ];
+    content.extend(lines);
+
+    let mut menu = Menu::new(content.join("\n"));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn submit_menu(year: u64, day: u64, part: Part, answer: &str) {
+    let verdict = match submit_answer(year, day, part, answer) {
+        Ok(verdict) => verdict,
+        Err(SubmitError::Data(e)) => {
+            return data_error_menu("Couldn't submit answer for current day.", &e)
+        }
+        Err(e) => return warn(&e.to_string()),
+    };
+
+    let color = match verdict {
+        Verdict::Correct => colored::Color::Green,
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => colored::Color::Red,
+        _ => colored::Color::Yellow,
+    };
+
+    let mut menu = Menu::new(verdict.to_string().color(color));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+/// Parses the official input once, then solves each of `parts` with it.
+fn run_parts(day: &AdventOfCodeDay, parts: &[Part], params: &Params, year: u64, day_number: u64) {
+    let data = match get_data(year, day_number) {
+        Ok(data) => data,
+        Err(e) => return data_error_menu("Couldn't load data for current day.", &e),
+    };
+
+    let start = Instant::now();
+    let parsed = match day.parse_with(&data, params) {
+        Some(Ok(parsed)) => parsed,
+        Some(Err(e)) => return solve_error_menu("Couldn't parse the input for current day.", &e),
+        None => return,
+    };
+    let parse_duration = start.elapsed();
+
+    let mut results = vec![];
+
+    for part in parts {
+        let start = Instant::now();
+
+        let Some(result) = day.solve(&parsed, *part) else {
+            continue;
+        };
+
+        results.push((*part, result, start.elapsed()));
+    }
+
+    results_menu(parse_duration, &results, day, params);
+}
+
+fn solve_error_menu(context: &str, e: &SolveError) {
+    let mut menu = Menu::new(format!("{}\n{}", context.red(), e.to_string().red()));
+
+    menu.add_back_option("Go Back");
+    menu.display();
+}
+
+fn data_error_menu(context: &str, e: &DataError) {
+    let mut menu = Menu::new(format!(
+        "{}\n{}\n{}",
+        context.red(),
+        e.to_string().red(),
+        e.next_step().yellow()
+    ));
+
+    menu.add_back_option("Go Back");
+    menu.display();
 }
@@ -1,7 +1,14 @@
-use std::{io, thread, time::Duration};
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    io, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use colored::Colorize;
 
+use crate::solves::unlock::civil_from_days;
+
 use super::banner::BANNER;
 
 pub fn clear() {
@@ -39,6 +46,21 @@ pub fn get_stdin_number() -> Option<i64> {
     None
 }
 
+/// The next line typed, trimmed.
+///
+/// # Panics
+/// If stdin can't be read.
+#[must_use]
+pub fn get_stdin_line() -> String {
+    let mut line = String::new();
+
+    io::stdin()
+        .read_line(&mut line)
+        .expect("Failed to read line");
+
+    line.trim().to_string()
+}
+
 pub fn invalid_option() {
     warn("Please select a valid option!");
 }
@@ -47,3 +69,61 @@ pub fn invalid_option() {
 pub fn format_result_runtime(result: &str, duration: Duration) -> String {
     format!("{result} - Elapsed Time: {}ms", duration.as_millis())
 }
+
+/// Durations in the unit that keeps them readable, like `312.4µs` or `1.52s`.
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1_000.0 {
+        format!("{micros:.1}µs")
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+#[must_use]
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[must_use]
+pub fn format_timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (year, month, day) = civil_from_days(seconds / (24 * 60 * 60));
+
+    format!(
+        "{year}-{month:02}-{day:02} {} UTC",
+        &format_countdown(Duration::from_secs(seconds % (24 * 60 * 60)))[..5]
+    )
+}
+
+/// Menu content that can still be changed by the menu's own options.
+pub struct SharedContent<'a>(pub &'a RefCell<String>);
+
+impl Display for SharedContent<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.borrow())
+    }
+}
+
+/// Menu option names that are worked out again every time the menu is shown.
+pub struct DisplayFn<F>(pub F);
+
+impl<F: Fn() -> String> Display for DisplayFn<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", (self.0)())
+    }
+}